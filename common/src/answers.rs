@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::Write;
+use std::path::Path;
+
+use crate::{ParseError, Part, parse_token};
+
+/// How an answer compares with the one recorded in an [`AnswerRegistry`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerCheck {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for AnswerCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => f.pad("PASS"),
+            Self::Fail { .. } => f.pad("FAIL"),
+            Self::Unknown => f.pad("UNKNOWN"),
+        }
+    }
+}
+
+/// Hashes normalised input lines with 64 bit FNV-1a, which unlike std's hashers is stable across releases
+pub fn input_hash<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for line in lines {
+        for byte in line.as_ref().bytes().chain([b'\n']) {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(PRIME);
+        }
+    }
+
+    hash
+}
+
+/// Percent encodes whitespace and `%` so an input name stays a single space separated token
+fn escape_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for chr in name.chars() {
+        if chr == '%' || chr.is_whitespace() {
+            for byte in chr.encode_utf8(&mut [0; 4]).bytes() {
+                escaped.push_str(&format!("%{byte:02X}"));
+            }
+        } else {
+            escaped.push(chr);
+        }
+    }
+
+    escaped
+}
+
+fn unescape_name(name: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(name.len());
+    let mut rest = name.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+#[derive(Clone, Debug)]
+struct RecordedAnswer {
+    input_hash: u64,
+    answer: String,
+}
+
+/// Confirmed answers keyed by day, part and input name, stored one per line as `day part input hash answer`
+/// with whitespace and `%` in the input name percent encoded
+///
+/// The [`input_hash`] of the contents is kept with each answer, so an input of the same name with
+/// different contents, such as another player's puzzle input, is never compared against it.
+#[derive(Clone, Debug, Default)]
+pub struct AnswerRegistry {
+    answers: BTreeMap<(u8, Part, String), RecordedAnswer>,
+}
+
+impl AnswerRegistry {
+    /// Loads a registry file, an empty registry is returned when the file does not exist yet
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+
+        Self::parse(&contents).map_err(std::io::Error::other)
+    }
+
+    /// Parses registry lines, skipping blank lines and `#` comments
+    pub fn parse(contents: &str) -> Result<Self, ParseError> {
+        let mut registry = Self::default();
+
+        for line in contents.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = trimmed.splitn(5, ' ').map(str::trim);
+            let mut next_token = |expected: &str| tokens.next()
+                .filter(|token| !token.is_empty())
+                .ok_or_else(|| ParseError::at_end(line, expected).within(contents, line));
+
+            let day = next_token("day")?;
+            let part = next_token("part")?;
+            let input = next_token("input name")?;
+            let hash = next_token("input hash")?;
+            let answer = next_token("answer")?;
+
+            let day = parse_token(line, day).map_err(|err| err.within(contents, line))?;
+            let part = parse_token(line, part).map_err(|err| err.within(contents, line))?;
+            let input = unescape_name(input)
+                .ok_or_else(|| ParseError::at(line, input, "percent encoded input name").within(contents, line))?;
+            let hash = u64::from_str_radix(hash, 16)
+                .map_err(|_| ParseError::at(line, hash, "hexadecimal input hash").within(contents, line))?;
+            registry.insert(day, part, &input, hash, answer);
+        }
+
+        Ok(registry)
+    }
+
+    /// The answer confirmed for an input of this name, provided its contents hash to `input_hash`
+    pub fn get(&self, day: u8, part: Part, input: &str, input_hash: u64) -> Option<&str> {
+        self.answers.get(&(day, part, input.to_string()))
+            .filter(|recorded| recorded.input_hash == input_hash)
+            .map(|recorded| recorded.answer.as_str())
+    }
+
+    /// Records `answer`, returning the answer it replaced
+    pub fn insert(&mut self, day: u8, part: Part, input: &str, input_hash: u64, answer: &str) -> Option<String> {
+        let recorded = RecordedAnswer {
+            input_hash,
+            answer: answer.to_string(),
+        };
+
+        self.answers.insert((day, part, input.to_string()), recorded)
+            .map(|replaced| replaced.answer)
+    }
+
+    pub fn check(&self, day: u8, part: Part, input: &str, input_hash: u64, answer: &str) -> AnswerCheck {
+        match self.get(day, part, input, input_hash) {
+            Some(expected) if expected == answer => AnswerCheck::Pass,
+            Some(expected) => AnswerCheck::Fail { expected: expected.to_string() },
+            None => AnswerCheck::Unknown,
+        }
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writeln!(writer, "# day part input hash answer")?;
+
+        for ((day, part, input), recorded) in &self.answers {
+            writeln!(writer, "{day} {part} {} {:016x} {}", escape_name(input), recorded.input_hash, recorded.answer)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let mut contents = Vec::new();
+        self.write(&mut contents)?;
+        std::fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Part;
+    use super::{AnswerCheck, AnswerRegistry, input_hash};
+
+    const TEST_REGISTRY: &str = "# day part input hash answer\n\
+        1 1 example 00000000000000aa 3\n\
+        \n\
+        1 2 input 00000000000000bb 6123\n";
+
+    #[test]
+    fn test_registry_check() {
+        let registry = AnswerRegistry::parse(TEST_REGISTRY).unwrap();
+
+        assert_eq!(registry.check(1, Part::One, "example", 0xaa, "3"), AnswerCheck::Pass);
+        assert_eq!(
+            registry.check(1, Part::Two, "input", 0xbb, "6124"),
+            AnswerCheck::Fail { expected: String::from("6123") }
+        );
+        assert_eq!(registry.check(1, Part::Two, "example", 0xaa, "6"), AnswerCheck::Unknown);
+    }
+
+    #[test]
+    fn test_registry_check_other_contents() {
+        let registry = AnswerRegistry::parse(TEST_REGISTRY).unwrap();
+        assert_eq!(registry.check(1, Part::Two, "input", 0xcc, "6124"), AnswerCheck::Unknown);
+    }
+
+    #[test]
+    fn test_registry_round_trip() {
+        let mut output = Vec::new();
+        AnswerRegistry::parse(TEST_REGISTRY).unwrap().write(&mut output).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), TEST_REGISTRY.replace("\n\n", "\n"));
+    }
+
+    #[test]
+    fn test_registry_escapes_input_names() {
+        let mut registry = AnswerRegistry::default();
+        registry.insert(1, Part::One, "my input\t100%.txt", 0xaa, "3");
+
+        let mut output = Vec::new();
+        registry.write(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("1 1 my%20input%09100%25.txt 00000000000000aa 3\n"));
+
+        let registry = AnswerRegistry::parse(&output).unwrap();
+        assert_eq!(registry.check(1, Part::One, "my input\t100%.txt", 0xaa, "3"), AnswerCheck::Pass);
+    }
+
+    #[test]
+    fn test_registry_error_position() {
+        let error = AnswerRegistry::parse("1 1 example 0a 3\n1 x example 0a 6").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 3);
+
+        let error = AnswerRegistry::parse("1 1 example 0a").unwrap_err();
+        assert_eq!(error.expected, "answer");
+
+        let error = AnswerRegistry::parse("1 1 example 3").unwrap_err();
+        assert_eq!(error.expected, "answer");
+
+        let error = AnswerRegistry::parse("1 1 example xyz 3").unwrap_err();
+        assert_eq!(error.column, 13);
+
+        let error = AnswerRegistry::parse("1 1 example%2 0a 3").unwrap_err();
+        assert_eq!(error.expected, "percent encoded input name");
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(Vec::<&str>::new()), 0xcbf29ce484222325);
+        assert_eq!(input_hash(["1", "2"]), input_hash("1\n2".lines()));
+        assert_ne!(input_hash(["1", "2"]), input_hash(["12"]));
+    }
+}
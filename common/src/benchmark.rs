@@ -0,0 +1,179 @@
+use std::any::type_name;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{
+    FallibleSolution,
+    Input,
+    ProblemQuestion,
+    SolveErrorKind
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct BenchmarkConfig {
+    /// Untimed runs made before sampling begins
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 10,
+            iterations: 100,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhaseStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl PhaseStats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let count = sorted.len();
+        if count == 0 {
+            return Self {
+                min: Duration::ZERO,
+                median: Duration::ZERO,
+                mean: Duration::ZERO,
+                stddev: Duration::ZERO,
+            };
+        }
+
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+
+        let mean_secs = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / count as f64;
+        let variance = sorted.iter()
+            .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>() / count as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Display for PhaseStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?} median {:>10.2?} mean {:>10.2?} stddev {:>10.2?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BenchmarkReport {
+    pub problem: &'static str,
+    pub solution: &'static str,
+    pub iterations: usize,
+    pub parse: PhaseStats,
+    pub answer: PhaseStats,
+    pub total: PhaseStats,
+}
+
+impl Display for BenchmarkReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} with {} over {} iterations", self.problem, self.solution, self.iterations)?;
+        writeln!(f, "Parse Input:        {}", self.parse)?;
+        writeln!(f, "Calculating Answer: {}", self.answer)?;
+        write!(f, "Total:              {}", self.total)
+    }
+}
+
+struct PhaseSample {
+    parse: Duration,
+    answer: Duration,
+}
+
+fn sample_phases<P, S>(input: &Input) -> Result<PhaseSample, SolveErrorKind>
+    where P: ProblemQuestion, S: FallibleSolution<P>
+{
+    let timer = Instant::now();
+    let parsed_input = black_box(input.parse::<P::Parser>()).map_err(SolveErrorKind::Parse)?;
+    let parse = timer.elapsed();
+
+    let timer = Instant::now();
+    let output = black_box(S::try_answer(parsed_input)).map_err(SolveErrorKind::Answer)?;
+    let answer = timer.elapsed();
+
+    drop(output);
+    Ok(PhaseSample { parse, answer })
+}
+
+/// Repeatedly solves `input`, reporting statistics over the timed iterations
+pub(crate) fn run_benchmark<P, S>(input: &Input, config: &BenchmarkConfig) -> Result<BenchmarkReport, SolveErrorKind>
+    where P: ProblemQuestion, S: FallibleSolution<P>
+{
+    for _ in 0..config.warmup {
+        sample_phases::<P, S>(input)?;
+    }
+
+    let samples = (0..config.iterations)
+        .map(|_| sample_phases::<P, S>(input))
+        .collect::<Result<Vec<PhaseSample>, SolveErrorKind>>()?;
+
+    let parse: Vec<Duration> = samples.iter().map(|sample| sample.parse).collect();
+    let answer: Vec<Duration> = samples.iter().map(|sample| sample.answer).collect();
+    let total: Vec<Duration> = samples.iter().map(|sample| sample.parse + sample.answer).collect();
+
+    Ok(BenchmarkReport {
+        problem: type_name::<P>(),
+        solution: type_name::<S>(),
+        iterations: config.iterations,
+        parse: PhaseStats::from_samples(&parse),
+        answer: PhaseStats::from_samples(&answer),
+        total: PhaseStats::from_samples(&total),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{BenchmarkConfig, Input, ProblemQuestion};
+    use crate::test_support::{SumProblem, SumSolution};
+    use super::PhaseStats;
+
+    #[test]
+    fn test_phase_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = PhaseStats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn test_benchmark_report() {
+        let config = BenchmarkConfig {
+            warmup: 2,
+            iterations: 5,
+        };
+        let report = SumProblem::benchmark::<SumSolution>(&Input::from_str("1\n2\n3"), &config).unwrap();
+
+        assert_eq!(report.iterations, 5);
+        assert!(report.parse.min <= report.parse.median);
+        assert!(report.answer.min <= report.answer.median);
+        assert!(report.total.min >= report.parse.min);
+    }
+}
@@ -0,0 +1,106 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Day, Input, ParseError, Part, parse_token};
+
+const EXPECTED_EXTENSION: &str = "expected";
+
+/// An input file paired with a `<name>.expected` sidecar holding one `part answer` line per checked part
+pub struct ExampleCase {
+    pub name: String,
+    pub input: Input,
+    pub expected: Vec<(Part, String)>,
+}
+
+impl ExampleCase {
+    /// Parses sidecar lines, skipping blank lines and `#` comments
+    pub fn parse_expected(contents: &str) -> Result<Vec<(Part, String)>, ParseError> {
+        contents.lines()
+            .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|line| {
+                let (part, answer) = line.trim()
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at_end(line, "answer").within(contents, line))?;
+
+                let part = parse_token(line, part).map_err(|err| err.within(contents, line))?;
+                Ok((part, answer.trim().to_string()))
+            })
+            .collect()
+    }
+
+    /// Loads every case in `dir` which has a sidecar, sorted by name
+    ///
+    /// A missing directory has no cases.
+    pub fn discover(dir: impl AsRef<Path>) -> std::io::Result<Vec<Self>> {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut sidecars: Vec<PathBuf> = entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<PathBuf>>>()?
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|extension| extension == EXPECTED_EXTENSION))
+            .collect();
+        sidecars.sort();
+
+        sidecars.into_iter()
+            .map(|sidecar| {
+                let input_path = sidecar.with_extension("");
+                let expected = Self::parse_expected(&std::fs::read_to_string(&sidecar)?)
+                    .map_err(|err| std::io::Error::other(format!("{}: {err}", sidecar.display())))?;
+
+                Ok(Self {
+                    name: input_path.file_name().unwrap_or_default().to_string_lossy().into_owned(),
+                    input: Input::from_file(&input_path)?.normalized(),
+                    expected,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Solves every case in `dir` with `D`, panicking with a list of every part which did not match its sidecar
+pub fn check_example_cases<D: Day>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let cases = ExampleCase::discover(dir)
+        .unwrap_or_else(|err| panic!("Could not load example cases from '{}': {err}", dir.display()));
+
+    let failures: Vec<String> = cases.iter()
+        .flat_map(|case| case.expected.iter().map(move |expected| (case, expected)))
+        .filter_map(|(case, (part, expected))| {
+            match D::solve(*part, case.input.clone()) {
+                Ok(report) if report.output == *expected => None,
+                Ok(report) => Some(format!("{} part {part}: expected {expected}, found {}", case.name, report.output)),
+                Err(err) => Some(format!("{} part {part}: {err}", case.name)),
+            }
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "Example cases failed\n{}", failures.join("\n"));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Part;
+    use super::ExampleCase;
+
+    #[test]
+    fn test_parse_expected() {
+        let expected = ExampleCase::parse_expected("# part answer\n1 3\n\n2 hello world\n").unwrap();
+        assert_eq!(
+            expected,
+            vec![(Part::One, String::from("3")), (Part::Two, String::from("hello world"))]
+        );
+
+        let error = ExampleCase::parse_expected("1 3\n3 6").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn test_discover_missing_directory() {
+        assert!(ExampleCase::discover("does/not/exist").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+use std::any::type_name;
+use std::fmt::Display;
+use log::info;
+
+use crate::{
+    FallibleSolution,
+    Input,
+    ProblemQuestion,
+    SolveError,
+    SolveReport
+};
+
+type SolveFn<P> = fn(Input) -> Result<(<P as ProblemQuestion>::Output, SolveReport), SolveError>;
+
+/// Runs several solutions of the same `ProblemQuestion` on one input so their answers and timings can be compared
+pub struct CrossCheck<P: ProblemQuestion> {
+    solutions: Vec<SolveFn<P>>,
+}
+
+impl<P: ProblemQuestion> Default for CrossCheck<P> {
+    fn default() -> Self {
+        Self {
+            solutions: Vec::new(),
+        }
+    }
+}
+
+impl<P: ProblemQuestion> CrossCheck<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with<S: FallibleSolution<P>>(mut self) -> Self {
+        self.solutions.push(P::solve_with_report::<S>);
+        self
+    }
+
+    /// Solves `input` with every registered solution in the order they were added
+    pub fn run(&self, input: &Input) -> Result<CrossCheckReport, SolveError> {
+        let reports = self.solutions.iter()
+            .map(|solve| solve(input.clone()).map(|(_, report)| report))
+            .collect::<Result<Vec<SolveReport>, SolveError>>()?;
+
+        Ok(CrossCheckReport { reports })
+    }
+
+    /// Runs every solution, logging the comparison table and panicking if any fails or they disagree
+    pub fn assert_agree(&self, input: &Input) -> CrossCheckReport {
+        let report = self.run(input).expect("Every solution finds an answer");
+        info!("Cross check of {}\n{report}\n", type_name::<P>());
+        assert!(report.agrees(), "Solutions disagree on the answer\n{report}");
+        report
+    }
+}
+
+pub struct CrossCheckReport {
+    pub reports: Vec<SolveReport>,
+}
+
+impl CrossCheckReport {
+    pub fn agrees(&self) -> bool {
+        self.reports.windows(2).all(|pair| pair[0].output == pair[1].output)
+    }
+}
+
+impl Display for CrossCheckReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fastest = self.reports.iter()
+            .map(|report| report.total)
+            .min()
+            .unwrap_or_default();
+
+        write!(
+            f,
+            "{:<40} {:>20} {:>12} {:>12} {:>12} {:>8}",
+            "Solution", "Output", "Parse", "Answer", "Total", "Relative"
+        )?;
+
+        for report in &self.reports {
+            let relative = report.total.as_secs_f64() / fastest.as_secs_f64().max(f64::MIN_POSITIVE);
+            write!(
+                f,
+                "\n{:<40} {:>20} {:>12} {:>12} {:>12} {:>8}",
+                report.solution,
+                report.output,
+                format!("{:.2?}", report.parse),
+                format!("{:.2?}", report.answer),
+                format!("{:.2?}", report.total),
+                format!("{relative:.2}x"),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Input, Solution};
+    use crate::test_support::{SumProblem, SumSolution};
+    use super::CrossCheck;
+
+    struct LoopSolution;
+    impl Solution<SumProblem> for LoopSolution {
+        fn answer(input: Vec<u32>) -> u32 {
+            let mut total = 0;
+            for value in input {
+                total += value;
+            }
+            total
+        }
+    }
+
+    struct MaxSolution;
+    impl Solution<SumProblem> for MaxSolution {
+        fn answer(input: Vec<u32>) -> u32 {
+            input.into_iter().max().unwrap_or_default()
+        }
+    }
+
+    const TEST_INPUT: Input = Input::from_str("1\n2\n3");
+
+    #[test]
+    fn test_cross_check_agrees() {
+        let report = CrossCheck::<SumProblem>::new()
+            .with::<SumSolution>()
+            .with::<LoopSolution>()
+            .assert_agree(&TEST_INPUT);
+
+        assert_eq!(report.reports.len(), 2);
+        assert!(report.to_string().contains("LoopSolution"));
+    }
+
+    #[test]
+    fn test_cross_check_disagrees() {
+        let report = CrossCheck::<SumProblem>::new()
+            .with::<SumSolution>()
+            .with::<MaxSolution>()
+            .run(&TEST_INPUT)
+            .unwrap();
+
+        assert!(!report.agrees());
+    }
+}
@@ -0,0 +1,132 @@
+/// Generates the boilerplate shared by every day crate
+///
+/// Expands to the `TEST_EXAMPLE` and `TEST_INPUT` constants loaded from the crate's `example`
+/// and `input` files, the `ProblemOne` and `ProblemTwo` questions, a [`Day`](crate::Day) impl
+/// dispatching each part to its solution, and tests checking both answers to the example as
+/// well as every case in the crate's `cases` directory (see [`ExampleCase`](crate::ExampleCase)).
+/// `example_solution` overrides the solution used by the example test when it differs, and
+/// `streaming_solution` names a [`StreamingSolution`](crate::StreamingSolution) which
+/// [`Day::solve_streaming`](crate::Day::solve_streaming) runs on lines as they are read.
+///
+/// ```ignore
+/// common::aoc_day! {
+///     DayOne = 1,
+///     ProblemOne {
+///         parser: DialInstruction,
+///         output: usize,
+///         solution: DialPasswordSolution,
+///         example: 3,
+///     },
+///     ProblemTwo {
+///         parser: DialInstruction,
+///         output: i32,
+///         solution: DialPasswordSolution,
+///         example: 6,
+///     },
+/// }
+/// ```
+#[macro_export]
+macro_rules! aoc_day {
+    (@or $chosen:ty; $default:ty) => { $chosen };
+    (@or ; $default:ty) => { $default };
+
+    (@stream $problem:ident, $arg:ident; $solution:ty) => {
+        Some(
+            <$problem as $crate::ProblemQuestion>::solve_streaming_with_report::<$solution>($crate::LineStream::from_arg($arg))
+                .map(|(_, report)| report)
+        )
+    };
+    (@stream $problem:ident, $arg:ident; ) => { None };
+
+    (
+        $day:ident = $number:literal,
+        ProblemOne {
+            parser: $parser_one:ty,
+            output: $output_one:ty,
+            solution: $solution_one:ty,
+            $(example_solution: $example_solution_one:ty,)?
+            $(streaming_solution: $streaming_solution_one:ty,)?
+            example: $example_one:expr $(,)?
+        },
+        ProblemTwo {
+            parser: $parser_two:ty,
+            output: $output_two:ty,
+            solution: $solution_two:ty,
+            $(example_solution: $example_solution_two:ty,)?
+            $(streaming_solution: $streaming_solution_two:ty,)?
+            example: $example_two:expr $(,)?
+        } $(,)?
+    ) => {
+        pub const TEST_EXAMPLE: $crate::Input = $crate::Input::from_str(include_str!("../example"));
+        pub const TEST_INPUT: $crate::Input = $crate::Input::from_str(include_str!("../input"));
+
+        struct ProblemOne;
+        impl $crate::ProblemQuestion for ProblemOne {
+            type Parser = $parser_one;
+            type Output = $output_one;
+        }
+
+        struct ProblemTwo;
+        impl $crate::ProblemQuestion for ProblemTwo {
+            type Parser = $parser_two;
+            type Output = $output_two;
+        }
+
+        pub struct $day;
+        impl $crate::Day for $day {
+            const NUMBER: u8 = $number;
+
+            fn visit_part<V: $crate::PartVisitor>(part: $crate::Part, visitor: V) -> V::Output {
+                match part {
+                    $crate::Part::One => visitor.visit::<ProblemOne, $solution_one>(),
+                    $crate::Part::Two => visitor.visit::<ProblemTwo, $solution_two>(),
+                }
+            }
+
+            fn stream_part(part: $crate::Part, _arg: &str) -> Option<Result<$crate::SolveReport, $crate::SolveError>> {
+                match part {
+                    $crate::Part::One => $crate::aoc_day!(@stream ProblemOne, _arg; $($streaming_solution_one)?),
+                    $crate::Part::Two => $crate::aoc_day!(@stream ProblemTwo, _arg; $($streaming_solution_two)?),
+                }
+            }
+        }
+
+        #[cfg(test)]
+        mod example_test {
+            use $crate::ProblemQuestion;
+
+            use super::*;
+
+            #[test]
+            fn test_problem_one_example() {
+                type ExampleSolution = $crate::aoc_day!(@or $($example_solution_one)?; $solution_one);
+
+                let result = ProblemOne::solve::<ExampleSolution>(TEST_EXAMPLE).unwrap();
+                assert_eq!(result, $example_one);
+            }
+
+            #[test]
+            fn test_problem_two_example() {
+                type ExampleSolution = $crate::aoc_day!(@or $($example_solution_two)?; $solution_two);
+
+                let result = ProblemTwo::solve::<ExampleSolution>(TEST_EXAMPLE).unwrap();
+                assert_eq!(result, $example_two);
+            }
+
+            #[test]
+            fn test_example_cases() {
+                $crate::check_example_cases::<$day>(concat!(env!("CARGO_MANIFEST_DIR"), "/cases"));
+            }
+        }
+    };
+}
+
+/// Generates a `main` running every part of a day crate's [`Day`](crate::Day) on its `TEST_INPUT`
+#[macro_export]
+macro_rules! aoc_main {
+    ($krate:ident :: $day:ident) => {
+        fn main() -> std::process::ExitCode {
+            <$krate::$day as $crate::Day>::run($krate::TEST_INPUT)
+        }
+    };
+}
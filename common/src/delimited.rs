@@ -0,0 +1,366 @@
+use std::{any::type_name, borrow::Cow, fmt::Display, marker::PhantomData, str::FromStr};
+
+use crate::{InputFormatter, InputParser, ParseError};
+
+/// Configures how a [`DelimitedParser`] splits its rows into fields
+pub trait RecordFormat {
+    const DELIMITER: char = ',';
+    /// Whether the first line names the columns and is skipped
+    const HEADER: bool = false;
+    /// Whether whitespace around each field is ignored
+    const TRIM: bool = false;
+    /// Fields wrapped in this character may contain the delimiter, doubling it escapes the quote
+    const QUOTE: Option<char> = Some('"');
+}
+
+/// Comma separated fields with optional double quotes
+pub struct Comma;
+
+impl RecordFormat for Comma {}
+
+/// A field split from a row, `raw` is the slice of the row it was read from including any quotes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelimitedField<'a> {
+    pub raw: &'a str,
+    pub value: Cow<'a, str>,
+}
+
+impl DelimitedField<'_> {
+    /// Parses the field's value with `FromStr`, positioning any error within `line`
+    pub fn parse<T: FromStr>(&self, line: &str) -> Result<T, ParseError> {
+        T::from_str(&self.value).map_err(|_| ParseError::at(line, self.raw, type_name::<T>()))
+    }
+}
+
+/// A row built from its delimited fields
+///
+/// Implemented for tuples and arrays of `FromStr` types, which must have exactly one field per
+/// element, and for `Vec`s which take any number. Structs can `#[derive(DelimitedRecord)]` to
+/// read their fields in declaration order.
+pub trait DelimitedRecord: Sized {
+    /// The number of fields in every row, `None` when rows may have any number
+    const FIELDS: Option<usize>;
+
+    fn from_fields(line: &str, fields: &[DelimitedField<'_>]) -> Result<Self, ParseError>;
+}
+
+impl<T: FromStr> DelimitedRecord for Vec<T> {
+    const FIELDS: Option<usize> = None;
+
+    fn from_fields(line: &str, fields: &[DelimitedField<'_>]) -> Result<Self, ParseError> {
+        fields.iter().map(|field| field.parse(line)).collect()
+    }
+}
+
+impl<T: FromStr, const N: usize> DelimitedRecord for [T; N] {
+    const FIELDS: Option<usize> = Some(N);
+
+    fn from_fields(line: &str, fields: &[DelimitedField<'_>]) -> Result<Self, ParseError> {
+        let values = Vec::<T>::from_fields(line, fields)?;
+        Ok(values.try_into().unwrap_or_else(|_| unreachable!("Arity is checked before building a record")))
+    }
+}
+
+/// A row which can be written back out as delimited fields, the inverse of [`DelimitedRecord`]
+pub trait FormatRecord {
+    fn format_fields(&self) -> Vec<String>;
+}
+
+impl<T: Display> FormatRecord for Vec<T> {
+    fn format_fields(&self) -> Vec<String> {
+        self.iter().map(ToString::to_string).collect()
+    }
+}
+
+impl<T: Display, const N: usize> FormatRecord for [T; N] {
+    fn format_fields(&self) -> Vec<String> {
+        self.iter().map(ToString::to_string).collect()
+    }
+}
+
+macro_rules! impl_tuple_record {
+    ($count:literal; $($element:ident $index:tt),+) => {
+        impl<$($element: FromStr,)+> DelimitedRecord for ($($element,)+) {
+            const FIELDS: Option<usize> = Some($count);
+
+            fn from_fields(line: &str, fields: &[DelimitedField<'_>]) -> Result<Self, ParseError> {
+                Ok(($(fields[$index].parse::<$element>(line)?,)+))
+            }
+        }
+
+        impl<$($element: Display,)+> FormatRecord for ($($element,)+) {
+            fn format_fields(&self) -> Vec<String> {
+                vec![$(self.$index.to_string(),)+]
+            }
+        }
+    };
+}
+
+impl_tuple_record!(1; A 0);
+impl_tuple_record!(2; A 0, B 1);
+impl_tuple_record!(3; A 0, B 1, C 2);
+impl_tuple_record!(4; A 0, B 1, C 2, E 3);
+impl_tuple_record!(5; A 0, B 1, C 2, E 3, F 4);
+impl_tuple_record!(6; A 0, B 1, C 2, E 3, F 4, G 5);
+
+/// Parses one [`DelimitedRecord`] per line, with fields split according to `F`
+pub struct DelimitedParser<R: DelimitedRecord, F: RecordFormat = Comma> {
+    _record: PhantomData<R>,
+    _format: PhantomData<F>,
+}
+
+/// Comma separated values of one type, any number per line
+pub type CSVParser<T> = DelimitedParser<Vec<T>>;
+
+impl<R: DelimitedRecord, F: RecordFormat> DelimitedParser<R, F> {
+    /// Parses a single row, checking it has the number of fields `R` expects
+    pub fn parse_record(line: &str) -> Result<R, ParseError> {
+        let fields = Self::split_fields(line)?;
+        Self::check_arity(line, &fields)?;
+        R::from_fields(line, &fields)
+    }
+
+    pub fn split_fields(line: &str) -> Result<Vec<DelimitedField<'_>>, ParseError> {
+        let mut fields = Vec::new();
+        let mut rest = line;
+
+        loop {
+            let start = Self::trim_start(rest);
+            let (field, after) = match F::QUOTE {
+                Some(quote) if start.starts_with(quote) => {
+                    let (field, after) = Self::split_quoted(line, start, quote)?;
+                    let after = Self::trim_start(after);
+                    if !after.is_empty() && !after.starts_with(F::DELIMITER) {
+                        return Err(ParseError::at(line, after, format!("{:?} after closing quote", F::DELIMITER)));
+                    }
+                    (field, after)
+                },
+                _ => {
+                    let end = rest.find(F::DELIMITER).unwrap_or(rest.len());
+                    let raw = Self::trim_end(Self::trim_start(&rest[..end]));
+                    (DelimitedField { raw, value: Cow::Borrowed(raw) }, &rest[end..])
+                },
+            };
+
+            fields.push(field);
+            match after.strip_prefix(F::DELIMITER) {
+                Some(after) => rest = after,
+                None => return Ok(fields),
+            }
+        }
+    }
+
+    /// Splits the quoted field at the start of `start`, returning it and what follows its closing quote
+    fn split_quoted<'a>(line: &str, start: &'a str, quote: char) -> Result<(DelimitedField<'a>, &'a str), ParseError> {
+        let body = &start[quote.len_utf8()..];
+        let mut unescaped: Option<String> = None;
+        let mut chunk_start = 0;
+        let mut chars = body.char_indices().peekable();
+
+        while let Some((index, chr)) = chars.next() {
+            if chr != quote {
+                continue;
+            }
+
+            let quote_end = index + quote.len_utf8();
+            if chars.next_if(|(_, next)| *next == quote).is_some() {
+                unescaped.get_or_insert_default().push_str(&body[chunk_start..quote_end]);
+                chunk_start = quote_end + quote.len_utf8();
+                continue;
+            }
+
+            let value = match unescaped {
+                Some(mut value) => {
+                    value.push_str(&body[chunk_start..index]);
+                    Cow::Owned(value)
+                },
+                None => Cow::Borrowed(&body[..index]),
+            };
+            let raw = &start[..quote.len_utf8() + quote_end];
+
+            return Ok((DelimitedField { raw, value }, &body[quote_end..]));
+        }
+
+        Err(ParseError::at_end(line, "closing quote"))
+    }
+
+    fn check_arity(line: &str, fields: &[DelimitedField<'_>]) -> Result<(), ParseError> {
+        let Some(expected) = R::FIELDS else {
+            return Ok(());
+        };
+
+        let message = || format!("{expected} fields separated by {:?}", F::DELIMITER);
+        match fields.get(expected) {
+            Some(extra) => Err(ParseError::at(line, extra.raw, format!("end of line after {}", message()))),
+            None if fields.len() < expected => Err(ParseError::at_end(line, message())),
+            None => Ok(()),
+        }
+    }
+
+    fn is_padding(chr: char) -> bool {
+        F::TRIM && chr.is_whitespace() && chr != F::DELIMITER
+    }
+
+    fn trim_start(value: &str) -> &str {
+        value.trim_start_matches(Self::is_padding)
+    }
+
+    fn trim_end(value: &str) -> &str {
+        value.trim_end_matches(Self::is_padding)
+    }
+}
+
+impl<R: DelimitedRecord, F: RecordFormat> InputParser for DelimitedParser<R, F> {
+    type Output = Vec<R>;
+
+    fn parse_input(input: &str) -> Result<Self::Output, ParseError> {
+        let mut lines = input.lines();
+        if F::HEADER {
+            let header = lines.next().ok_or_else(|| ParseError::at_end(input, "header row"))?;
+            Self::split_fields(header)
+                .and_then(|fields| Self::check_arity(header, &fields))
+                .map_err(|err| err.within(input, header))?;
+        }
+
+        lines
+            .map(|line| Self::parse_record(line).map_err(|err| err.within(input, line)))
+            .collect()
+    }
+}
+
+impl<R: DelimitedRecord + FormatRecord, F: RecordFormat> DelimitedParser<R, F> {
+    /// Writes a single row, quoting fields which would otherwise be split or trimmed differently
+    pub fn format_record(record: &R) -> String {
+        let fields: Vec<String> = record.format_fields()
+            .into_iter()
+            .map(|field| Self::quote_field(field))
+            .collect();
+
+        fields.join(&F::DELIMITER.to_string())
+    }
+
+    fn quote_field(field: String) -> String {
+        let Some(quote) = F::QUOTE else {
+            return field;
+        };
+
+        let is_padded = field.starts_with(Self::is_padding) || field.ends_with(Self::is_padding);
+        if !field.contains(F::DELIMITER) && !field.starts_with(quote) && !is_padded {
+            return field;
+        }
+
+        let escaped = field.replace(quote, &format!("{quote}{quote}"));
+        format!("{quote}{escaped}{quote}")
+    }
+}
+
+/// Rows are written one per line, after a header naming the columns `1`, `2` and so on when `F` has one
+impl<R: DelimitedRecord + FormatRecord, F: RecordFormat> InputFormatter for DelimitedParser<R, F> {
+    fn format_input(output: &Self::Output) -> String {
+        let mut formatted = String::new();
+        if F::HEADER {
+            let columns = R::FIELDS
+                .or_else(|| output.first().map(|record| record.format_fields().len()))
+                .unwrap_or(1);
+            let header: Vec<String> = (1..=columns).map(|column| column.to_string()).collect();
+            formatted.push_str(&header.join(&F::DELIMITER.to_string()));
+            formatted.push('\n');
+        }
+
+        for record in output {
+            formatted.push_str(&Self::format_record(record));
+            formatted.push('\n');
+        }
+
+        formatted
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use crate::{DelimitedRecord, InputParser, assert_round_trip};
+    use super::{CSVParser, DelimitedParser, RecordFormat};
+
+    struct Tabs;
+    impl RecordFormat for Tabs {
+        const DELIMITER: char = '\t';
+        const HEADER: bool = true;
+        const TRIM: bool = true;
+    }
+
+    #[derive(Debug, PartialEq, DelimitedRecord)]
+    struct Sample {
+        name: String,
+        count: u32,
+        weight: f32,
+    }
+
+    #[test]
+    fn test_tuple_records() {
+        let rows = DelimitedParser::<(i64, i64, i64)>::parse_input("1,2,3\n-4,5,6").unwrap();
+        assert_eq!(rows, vec![(1, 2, 3), (-4, 5, 6)]);
+
+        let rows = DelimitedParser::<[u8; 2]>::parse_input("1,2").unwrap();
+        assert_eq!(rows, vec![[1, 2]]);
+    }
+
+    #[test]
+    fn test_arity_errors() {
+        let error = DelimitedParser::<(u32, u32)>::parse_input("1,2\n3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "2 fields separated by ','");
+
+        let error = DelimitedParser::<(u32, u32)>::parse_input("1,2,3").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.snippet, "3");
+    }
+
+    #[test]
+    fn test_quoted_fields() {
+        type Parser = DelimitedParser<Vec<String>>;
+
+        let fields = Parser::split_fields(r#"plain,"a, b","say ""hi""""#).unwrap();
+        let values: Vec<&str> = fields.iter().map(|field| field.value.as_ref()).collect();
+        assert_eq!(values, vec!["plain", "a, b", r#"say "hi""#]);
+        assert!(matches!(fields[1].value, Cow::Borrowed(_)));
+        assert_eq!(fields[2].raw, r#""say ""hi""""#);
+
+        let error = Parser::parse_input("a,\"b").unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(error.expected, "closing quote");
+
+        let error = Parser::parse_input("\"a\"b,c").unwrap_err();
+        assert_eq!(error.column, 4);
+    }
+
+    #[test]
+    fn test_format_round_trip() {
+        assert_round_trip::<CSVParser<String>>(&vec![
+            vec![String::from("a, b"), String::from("say \"hi\"")],
+            vec![String::from("plain")],
+        ]);
+        assert_round_trip::<DelimitedParser<(String, i8), Tabs>>(&vec![(String::from(" padded "), -3)]);
+
+        let formatted = DelimitedParser::<(String, u8)>::format_record(&(String::from("x,y"), 4));
+        assert_eq!(formatted, "\"x,y\",4");
+    }
+
+    #[test]
+    fn test_struct_records_with_header() {
+        let input = "name\tcount\tweight\n  apple \t 3\t0.5\n\"pear\tgreen\"\t1\t2\n";
+        let rows = DelimitedParser::<Sample, Tabs>::parse_input(input).unwrap();
+        assert_eq!(rows, vec![
+            Sample { name: String::from("apple"), count: 3, weight: 0.5 },
+            Sample { name: String::from("pear\tgreen"), count: 1, weight: 2.0 },
+        ]);
+
+        let error = DelimitedParser::<Sample, Tabs>::parse_input("name\tcount\tweight\nfig\tx\t1").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.expected, "u32");
+
+        let error = DelimitedParser::<Sample, Tabs>::parse_input("name\tcount\n").unwrap_err();
+        assert_eq!(error.line, 1);
+    }
+}
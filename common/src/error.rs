@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+const SNIPPET_LENGTH: usize = 20;
+
+/// A parse failure, positioned by 1-indexed line and column within the parsed input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error found at the start of `position`, which must be a slice of `source`
+    pub fn at(source: &str, position: &str, expected: impl Into<String>) -> Self {
+        let offset = byte_offset(source, position);
+        let consumed = &source[..offset];
+        let line_start = consumed.rfind('\n').map_or(0, |index| index + 1);
+
+        let snippet = position.lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(SNIPPET_LENGTH)
+            .collect();
+
+        Self {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed[line_start..].chars().count() + 1,
+            snippet,
+            expected: expected.into(),
+        }
+    }
+
+    /// Error found at the very end of `source`
+    pub fn at_end(source: &str, expected: impl Into<String>) -> Self {
+        Self::at(source, &source[source.len()..], expected)
+    }
+
+    /// Repositions an error reported against `section` to be relative to `source` which contains it
+    pub fn within(mut self, source: &str, section: &str) -> Self {
+        let section_start = Self::at(source, section, "");
+
+        if self.line == 1 {
+            self.column += section_start.column - 1;
+        }
+        self.line += section_start.line - 1;
+
+        self
+    }
+
+    pub fn from_nom(source: &str, error: nom::Err<nom::error::Error<&str>>, expected: &str) -> Self {
+        match error {
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                if err.code == nom::error::ErrorKind::Eof {
+                    Self::at(source, err.input, "end of line")
+                } else {
+                    Self::at(source, err.input, expected)
+                }
+            },
+            nom::Err::Incomplete(_) => Self::at_end(source, expected),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (line, column, expected) = (self.line, self.column, &self.expected);
+        write!(f, "line {line}, column {column}: expected {expected}, ")?;
+
+        if self.snippet.is_empty() {
+            write!(f, "found end of line")
+        } else {
+            write!(f, "found '{}'", self.snippet)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn byte_offset(source: &str, position: &str) -> usize {
+    let start = source.as_ptr() as usize;
+    let offset = (position.as_ptr() as usize).saturating_sub(start);
+
+    debug_assert!(offset <= source.len(), "Position lies within source");
+    offset.min(source.len())
+}
+
+/// Reported by a `FallibleSolution` when no answer exists for its input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolutionError {
+    pub reason: String,
+}
+
+impl SolutionError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into()
+        }
+    }
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl std::error::Error for SolutionError {}
+
+/// Why a `ProblemQuestion` could not be solved
+#[derive(Debug)]
+pub enum SolveErrorKind {
+    Parse(ParseError),
+    Answer(SolutionError),
+    Io(std::io::Error),
+}
+
+#[derive(Debug)]
+pub struct SolveError {
+    pub problem: &'static str,
+    pub solution: &'static str,
+    pub kind: SolveErrorKind,
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (problem, solution) = (self.problem, self.solution);
+        match &self.kind {
+            SolveErrorKind::Parse(err) => write!(f, "Solving {problem} with {solution} failed to parse input at {err}"),
+            SolveErrorKind::Answer(err) => write!(f, "Solving {problem} with {solution} found no answer: {err}"),
+            SolveErrorKind::Io(err) => write!(f, "Solving {problem} with {solution} could not read input: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+#[cfg(test)]
+mod test {
+    use super::ParseError;
+
+    #[test]
+    fn test_error_position() {
+        let source = "123\n456\n7x9";
+        let error = ParseError::at(source, &source[9..], "digit");
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 2);
+        assert_eq!(error.snippet, "x9");
+        assert_eq!(error.to_string(), "line 3, column 2: expected digit, found 'x9'");
+    }
+
+    #[test]
+    fn test_error_within_section() {
+        let source = "abc\n\ndef ghi";
+        let section = &source[9..];
+        let error = ParseError::at(section, &section[1..], "letter")
+            .within(source, section);
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 6);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::ParseError;
+
+/// A piece of a `#[format("...")]` pattern generated by `#[derive(LineParse)]`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormatSegment {
+    Literal(&'static str),
+    /// A placeholder, holding the index of the field it fills
+    Field(usize),
+}
+
+struct SplitState<'a, 'f> {
+    line: &'a str,
+    segments: &'f [FormatSegment],
+    expected: &'f [&'f str],
+    accept: &'f mut dyn FnMut(usize, &str) -> bool,
+    tokens: Vec<&'a str>,
+    furthest: Option<(usize, String)>,
+}
+
+impl<'a> SplitState<'a, '_> {
+    fn fail(&mut self, position: usize, expected: String) -> bool {
+        if self.furthest.as_ref().is_none_or(|(furthest, _)| position >= *furthest) {
+            self.furthest = Some((position, expected));
+        }
+        false
+    }
+
+    fn split_from(&mut self, segment: usize, position: usize) -> bool {
+        let rest = &self.line[position..];
+
+        match self.segments.get(segment) {
+            None if rest.is_empty() => true,
+            None => self.fail(position, String::from("end of line")),
+            Some(FormatSegment::Literal(literal)) if rest.starts_with(literal) => {
+                self.split_from(segment + 1, position + literal.len())
+            },
+            Some(FormatSegment::Literal(literal)) => self.fail(position, format!("{literal:?}")),
+            Some(FormatSegment::Field(field)) => {
+                let field = *field;
+                let ends: Vec<usize> = match self.segments.get(segment + 1) {
+                    None => vec![rest.len()],
+                    Some(FormatSegment::Literal(literal)) => rest.match_indices(literal)
+                        .map(|(index, _)| index)
+                        .collect(),
+                    Some(FormatSegment::Field(_)) => {
+                        let mut ends: Vec<usize> = rest.char_indices()
+                            .map(|(index, _)| index)
+                            .skip(1)
+                            .chain([rest.len()])
+                            .collect();
+                        ends.reverse();
+                        ends
+                    },
+                };
+
+                for end in ends.into_iter().filter(|end| *end > 0) {
+                    let token = &rest[..end];
+                    if !(self.accept)(field, token) {
+                        continue;
+                    }
+
+                    self.tokens.push(token);
+                    if self.split_from(segment + 1, position + end) {
+                        return true;
+                    }
+                    self.tokens.pop();
+                }
+
+                self.fail(position, self.expected[field].to_string())
+            },
+        }
+    }
+}
+
+/// Splits `line` into one token per field of `segments`, in pattern order
+///
+/// Fields are matched up to the next literal, or by trying every split when two fields are
+/// adjacent; `accept` decides whether a token can be parsed into its field, and is called last
+/// for each field with the token returned for it. On failure the error is positioned at the
+/// furthest point any attempted split reached.
+pub fn split_format<'a>(
+    line: &'a str,
+    segments: &[FormatSegment],
+    expected: &[&str],
+    accept: &mut dyn FnMut(usize, &str) -> bool,
+) -> Result<Vec<&'a str>, ParseError> {
+    let mut state = SplitState {
+        line,
+        segments,
+        expected,
+        accept,
+        tokens: Vec::new(),
+        furthest: None,
+    };
+
+    if state.split_from(0, 0) {
+        return Ok(state.tokens);
+    }
+
+    let (position, expected) = state.furthest.unwrap_or_else(|| (0, String::from("line")));
+    Err(ParseError::at(line, &line[position..], expected))
+}
+
+/// Picks the error which got furthest into the line, joining the expectations of equally far errors
+pub fn furthest_error(errors: Vec<ParseError>) -> ParseError {
+    let furthest = errors.iter()
+        .map(|err| (err.line, err.column))
+        .max()
+        .expect("At least one error");
+
+    let mut furthest_errors = errors.into_iter().filter(|err| (err.line, err.column) == furthest);
+    let mut error = furthest_errors.next().expect("At least one error");
+    for other in furthest_errors {
+        if !error.expected.split(" or ").any(|expected| expected == other.expected) {
+            error.expected = format!("{} or {}", error.expected, other.expected);
+        }
+    }
+
+    error
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::{LineInputParser, LineParse};
+    use super::{FormatSegment, split_format};
+
+    #[derive(Debug, PartialEq, LineParse)]
+    enum Turn {
+        #[format("L")]
+        Left,
+        #[format("R")]
+        Right,
+    }
+
+    #[derive(Debug, PartialEq, LineParse)]
+    #[format("{turn}{distance}")]
+    struct Move {
+        turn: Turn,
+        distance: u32,
+    }
+
+    #[derive(Debug, PartialEq, LineParse)]
+    enum Command {
+        #[format("move {0} then {1}")]
+        Twice(Move, Move),
+        #[format("wait {{{ticks}}}")]
+        Wait { ticks: u8 },
+    }
+
+    static COUNTED_PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, PartialEq)]
+    struct Counted(u32);
+
+    impl FromStr for Counted {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            COUNTED_PARSES.fetch_add(1, Ordering::Relaxed);
+            s.parse().map(Counted).map_err(|_| ())
+        }
+    }
+
+    #[derive(Debug, PartialEq, LineParse)]
+    #[format("{0}-{1}")]
+    struct Span(Counted, Counted);
+
+    fn accept_digits(_: usize, token: &str) -> bool {
+        token.parse::<u32>().is_ok()
+    }
+
+    #[test]
+    fn test_split_literal_separated() {
+        let segments = [FormatSegment::Field(0), FormatSegment::Literal("-"), FormatSegment::Field(1)];
+        let tokens = split_format("12-345", &segments, &["u32", "u32"], &mut accept_digits).unwrap();
+        assert_eq!(tokens, vec!["12", "345"]);
+    }
+
+    #[test]
+    fn test_split_adjacent_fields() {
+        let segments = [FormatSegment::Field(0), FormatSegment::Field(1)];
+        let mut accept = |field: usize, token: &str| match field {
+            0 => token.len() == 1 && token.chars().all(char::is_alphabetic),
+            _ => token.parse::<u32>().is_ok(),
+        };
+
+        let tokens = split_format("L68", &segments, &["letter", "u32"], &mut accept).unwrap();
+        assert_eq!(tokens, vec!["L", "68"]);
+    }
+
+    #[test]
+    fn test_split_error_position() {
+        let segments = [FormatSegment::Literal("x="), FormatSegment::Field(0), FormatSegment::Literal(",")];
+        let error = split_format("x=12;", &segments, &["u32"], &mut accept_digits).unwrap_err();
+        assert_eq!(error.column, 3);
+        assert_eq!(error.expected, "u32");
+
+        let error = split_format("y=12,", &segments, &["u32"], &mut accept_digits).unwrap_err();
+        assert_eq!(error.column, 1);
+        assert_eq!(error.expected, "\"x=\"");
+    }
+
+    #[test]
+    fn test_derive_struct() {
+        let parsed = Move::parse_line("R68").unwrap();
+        assert_eq!(parsed, Move { turn: Turn::Right, distance: 68 });
+    }
+
+    #[test]
+    fn test_derive_enum() {
+        let parsed = Command::parse_line("move L1 then R20").unwrap();
+        assert_eq!(
+            parsed,
+            Command::Twice(Move { turn: Turn::Left, distance: 1 }, Move { turn: Turn::Right, distance: 20 })
+        );
+        assert_eq!(Command::parse_line("wait {7}").unwrap(), Command::Wait { ticks: 7 });
+    }
+
+    #[test]
+    fn test_derive_error() {
+        let error = Move::parse_line("X5").unwrap_err();
+        assert_eq!(error.column, 1);
+
+        let error = Command::parse_line("halt").unwrap_err();
+        assert_eq!(error.column, 1);
+        assert_eq!(error.expected, "\"move \" or \"wait {\"");
+    }
+
+    #[test]
+    fn test_derive_parses_once() {
+        let parsed = Span::parse_line("3-14").unwrap();
+        assert_eq!(parsed, Span(Counted(3), Counted(14)));
+        assert_eq!(COUNTED_PARSES.load(Ordering::Relaxed), 2);
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::fmt::{Debug, Display};
 use std::str::FromStr;
@@ -5,7 +6,8 @@ use std::str::FromStr;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-use crate::InputParser;
+use crate::{InputFormatter, InputParser, ParseError};
+use crate::parser::parse_token;
 
 
 pub struct GridUnit;
@@ -115,7 +117,91 @@ impl Direction for OrdinalDirection {
 }
 
 
-#[derive(Clone)]
+/// Access to a 2D grid independent of how it stores its elements, so algorithms can be written once
+pub trait Grid {
+    type Element;
+
+    /// A rect containing every point which holds an element
+    ///
+    /// A grid which wraps, such as [`WrappingGrid2D`], holds an element at every point, its rect is
+    /// then the canonical tile which every other point maps onto.
+    fn rect(&self) -> Rect;
+
+    fn get(&self, point: Point2D) -> Option<&Self::Element>;
+    fn get_mut(&mut self, point: Point2D) -> Option<&mut Self::Element>;
+
+    /// Every point which holds an element, visiting only the canonical tile of a wrapping grid
+    fn point_iter(&self) -> impl Iterator<Item = Point2D>;
+
+    /// The points one step from `point` in each of `D`'s directions which the grid can hold
+    fn point_neighbours<D: Direction>(&self, point: Point2D) -> impl Iterator<Item = Point2D>;
+
+    fn contains(&self, point: Point2D) -> bool {
+        self.get(point).is_some()
+    }
+
+    fn element_iter(&self) -> impl Iterator<Item = (&Self::Element, Point2D)> {
+        self.point_iter()
+            .filter_map(|point| self.get(point).map(|element| (element, point)))
+    }
+
+    /// The neighbouring points which hold an element
+    fn element_neighbours<D: Direction>(
+        &self, 
+        point: Point2D, 
+    ) -> impl Iterator<Item = (&Self::Element, Point2D)> {
+        self.point_neighbours::<D>(point)
+            .filter_map(|neighbour| self.get(neighbour).map(|element| (element, neighbour)))
+    }
+
+    fn element_iter_filtered(
+        &self, 
+        search_type: &Self::Element
+    ) -> impl Iterator<Item = (&Self::Element, Point2D)> 
+        where Self::Element: PartialEq
+    {
+        self.element_iter()
+            .filter(move |(element, _)| **element == *search_type)
+    }
+
+    fn element_neighbours_filtered<D: Direction>(
+        &self, 
+        point: Point2D, 
+        search_type: &Self::Element
+    ) -> impl Iterator<Item = (&Self::Element, Point2D)> 
+        where Self::Element: PartialEq
+    {
+        self.element_neighbours::<D>(point)
+            .filter(move |(neighbour, _)| **neighbour == *search_type)
+    }
+
+    /// Prints the grid with each of `points` drawn as its character, `.` marks points without an element
+    fn highlight(
+        &self,
+        points: Vec<(Point2D, char)>,
+    ) 
+        where Self::Element: Display
+    {
+        let rect = self.rect();
+        for y in rect.min_y()..rect.max_y() {
+            for x in rect.min_x()..rect.max_x() {
+                let point = Point2D::new(x, y);
+                let highlight = points.iter()
+                    .find(|(p, _)| point == *p);
+
+                match (highlight, self.get(point)) {
+                    (Some((_, highlight_chr)), _) => print!("{highlight_chr}"),
+                    (None, Some(element)) => print!("{element}"),
+                    (None, None) => print!("."),
+                }
+            }
+
+            println!();
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Grid2D<T> {
     // Invariant: elements.len() == width*height
     pub elements: Vec<T>,
@@ -161,29 +247,63 @@ impl<T> Grid2D<T> {
                 .map(move |y| Point2D::new(x, y))
         })
     }
+}
 
-    pub fn element_iter(&self) -> impl Iterator<Item = (&T, Point2D)> {
-        self.point_iter()
-            .map(|point| (self.get(point).unwrap(), point))
+impl<T> Grid2D<T> {
+    /// The point which `point` lands on when the grid repeats in every direction, so moving off one
+    /// edge re-enters on the opposite edge. `None` when the grid is empty
+    pub fn wrap(&self, point: Point2D) -> Option<Point2D> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+
+        Some(Point2D::new(point.x.rem_euclid(self.width), point.y.rem_euclid(self.height)))
+    }
+
+    pub fn get_wrapped(&self, point: Point2D) -> Option<&T> {
+        self.wrap(point).and_then(|point| self.get(point))
     }
 
-    pub fn point_neighbours<D: Direction>(
+    pub fn get_wrapped_mut(&mut self, point: Point2D) -> Option<&mut T> {
+        self.wrap(point).and_then(|point| self.get_mut(point))
+    }
+
+    /// Neighbours in each of `D`'s directions, wrapping across the edges so there is always one per direction
+    pub fn point_neighbours_wrapped<D: Direction>(
         &self, 
         point: Point2D, 
     ) -> impl Iterator<Item = Point2D> {
-        D::iter()
-            .map(move |dir| point + dir.delta())
-            .filter(|neighbour| self.rect().contains(*neighbour))
+        D::iter().filter_map(move |dir| self.wrap(point + dir.delta()))
+    }
+}
+
+impl<T> Grid for Grid2D<T> {
+    type Element = T;
+
+    fn rect(&self) -> Rect {
+        Grid2D::rect(self)
+    }
+
+    fn get(&self, point: Point2D) -> Option<&T> {
+        Grid2D::get(self, point)
+    }
+
+    fn get_mut(&mut self, point: Point2D) -> Option<&mut T> {
+        Grid2D::get_mut(self, point)
     }
 
-    pub fn element_neighbours<D: Direction>(
+    fn point_iter(&self) -> impl Iterator<Item = Point2D> {
+        Grid2D::point_iter(self)
+    }
+
+    fn point_neighbours<D: Direction>(
         &self, 
         point: Point2D, 
-    ) -> impl Iterator<Item = (&T, Point2D)> {
-        self.point_neighbours::<D>(point)
-            .map(|neighbour| (self.get(neighbour).unwrap(), neighbour))
+    ) -> impl Iterator<Item = Point2D> {
+        D::iter()
+            .map(move |dir| point + dir.delta())
+            .filter(|neighbour| self.rect().contains(*neighbour))
     }
-
 }
 
 impl<T: Display> Display for Grid2D<T> {
@@ -201,48 +321,186 @@ impl<T: Display> Display for Grid2D<T> {
     }
 }
 
-impl<T: Display> Grid2D<T> {
-    pub fn highlight(
-        &self,
-        points: Vec<(Point2D, char)>,
-    ) {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let point = Point2D::new(x, y);
-                let element = self.get(point).unwrap();
+/// A [`Grid2D`] whose edges wrap around, so every point maps onto the grid
+#[derive(Clone, Debug, PartialEq)]
+pub struct WrappingGrid2D<T> {
+    pub grid: Grid2D<T>,
+}
 
-                let highlight = points.iter()
-                    .filter(|(p, _)| point == *p)
-                    .next();
+impl<T> WrappingGrid2D<T> {
+    pub fn new(grid: Grid2D<T>) -> Self {
+        Self {
+            grid
+        }
+    }
 
-                if let Some((_, highlight_chr)) = highlight {
-                    print!("{highlight_chr}");
-                } else {
-                    print!("{element}");
-                }
-            }
+    pub fn into_inner(self) -> Grid2D<T> {
+        self.grid
+    }
+}
 
-            println!();
+impl<T> Grid for WrappingGrid2D<T> {
+    type Element = T;
+
+    fn rect(&self) -> Rect {
+        self.grid.rect()
+    }
+
+    fn get(&self, point: Point2D) -> Option<&T> {
+        self.grid.get_wrapped(point)
+    }
+
+    fn get_mut(&mut self, point: Point2D) -> Option<&mut T> {
+        self.grid.get_wrapped_mut(point)
+    }
+
+    fn point_iter(&self) -> impl Iterator<Item = Point2D> {
+        self.grid.point_iter()
+    }
+
+    fn point_neighbours<D: Direction>(
+        &self, 
+        point: Point2D, 
+    ) -> impl Iterator<Item = Point2D> {
+        self.grid.point_neighbours_wrapped::<D>(point)
+    }
+}
+
+impl<T: Display> Display for WrappingGrid2D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+/// An unbounded grid holding elements at any point, including negative ones
+///
+/// Iteration visits points in no particular order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseGrid2D<T> {
+    pub elements: HashMap<Point2D, T>,
+}
+
+impl<T> Default for SparseGrid2D<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid2D<T> {
+    pub fn new() -> Self {
+        Self {
+            elements: HashMap::new(),
         }
     }
+
+    /// The smallest rect containing every element, empty when the grid is
+    pub fn rect(&self) -> Rect {
+        let mut points = self.elements.keys();
+        let Some(first) = points.next() else {
+            return Rect::zero();
+        };
+
+        let (min, max) = points.fold((*first, *first), |(min, max), point| (min.min(*point), max.max(*point)));
+        Rect::new(min, (max - min + Vector2D::new(1, 1)).to_size())
+    }
+
+    pub fn size(&self) -> Size2D {
+        self.rect().size
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn get(&self, point: Point2D) -> Option<&T> {
+        self.elements.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point2D) -> Option<&mut T> {
+        self.elements.get_mut(&point)
+    }
+
+    /// Places `element` at `point`, returning the element it replaced
+    pub fn insert(&mut self, point: Point2D, element: T) -> Option<T> {
+        self.elements.insert(point, element)
+    }
+
+    pub fn remove(&mut self, point: Point2D) -> Option<T> {
+        self.elements.remove(&point)
+    }
+
+    pub fn point_iter(&self) -> impl Iterator<Item = Point2D> {
+        self.elements.keys().copied()
+    }
 }
 
-impl<T: PartialEq> Grid2D<T> {
-    pub fn element_iter_filtered(
-        &self,
-        search_type: &T
-    ) -> impl Iterator<Item = (&T, Point2D)> {
-        self.element_iter()
-            .filter(|(element, _)| **element == *search_type)
+impl<T> Grid for SparseGrid2D<T> {
+    type Element = T;
+
+    fn rect(&self) -> Rect {
+        SparseGrid2D::rect(self)
+    }
+
+    fn get(&self, point: Point2D) -> Option<&T> {
+        SparseGrid2D::get(self, point)
+    }
+
+    fn get_mut(&mut self, point: Point2D) -> Option<&mut T> {
+        SparseGrid2D::get_mut(self, point)
+    }
+
+    fn point_iter(&self) -> impl Iterator<Item = Point2D> {
+        SparseGrid2D::point_iter(self)
     }
 
-    pub fn element_neighbours_filtered<D: Direction>(
+    /// Every neighbouring point, whether or not it holds an element, as the grid has no edges
+    fn point_neighbours<D: Direction>(
         &self, 
         point: Point2D, 
-        search_type: &T
-    ) -> impl Iterator<Item = (&T, Point2D)> {
-        self.element_neighbours::<D>(point)
-            .filter(|(neighbour, _)| **neighbour == *search_type)
+    ) -> impl Iterator<Item = Point2D> {
+        D::iter().map(move |dir| point + dir.delta())
+    }
+
+    fn element_iter(&self) -> impl Iterator<Item = (&T, Point2D)> {
+        self.elements.iter().map(|(point, element)| (element, *point))
+    }
+}
+
+/// Draws the bounding rect, with `.` for points which hold no element
+impl<T: Display> Display for SparseGrid2D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rect = self.rect();
+        for y in rect.min_y()..rect.max_y() {
+            for x in rect.min_x()..rect.max_x() {
+                match self.get(Point2D::new(x, y)) {
+                    Some(element) => write!(f, "{element}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> FromIterator<(Point2D, T)> for SparseGrid2D<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2D, T)>>(iter: I) -> Self {
+        Self {
+            elements: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> From<Grid2D<T>> for SparseGrid2D<T> {
+    fn from(grid: Grid2D<T>) -> Self {
+        let points: Vec<Point2D> = grid.point_iter().collect();
+        points.into_iter().zip(grid.elements).collect()
     }
 }
 
@@ -250,28 +508,33 @@ pub struct Grid2DParser<T> {
     pub _element_type: PhantomData<T>
 }
 
-impl<T: TryFrom<char>> InputParser for Grid2DParser<T>
-    where <T as TryFrom<char>>::Error: std::fmt::Debug
-{
-    type Output = Grid2D<T>;
+impl<T: TryFrom<char>> Grid2DParser<T> {
+    fn parse_line(line: &str) -> Result<Vec<T>, ParseError> {
+        line.char_indices()
+            .map(|(index, chr)| {
+                T::try_from(chr)
+                    .map_err(|_| ParseError::at(line, &line[index..], "valid grid character"))
+            })
+            .collect()
+    }
+}
 
-    fn parse_input(input: &str) -> Self::Output {
-        let lines: Vec<&str> = input.lines().collect();
-        let height = lines.len() as isize;
-        let width = lines.get(0).expect("At least one line of input").len() as isize;
+impl<T: TryFrom<char>> InputParser for Grid2DParser<T> {
+    type Output = Grid2D<T>;
 
-        let elements: Vec<T> = lines.iter()
-            .flat_map(|line| line.chars())
-            .map(|chr| T::try_from(chr).expect("Input is all valid characters"))
-            .collect();
+    fn parse_input(input: &str) -> Result<Self::Output, ParseError> {
+        let grid_lines = input.lines()
+            .map(|line| Self::parse_line(line).map_err(|err| err.within(input, line)))
+            .collect::<Result<Vec<Vec<T>>, ParseError>>()?;
 
-        assert_eq!(width * height, elements.len() as isize);
+        Grid2D::from_rows(input, grid_lines)
+    }
+}
 
-        Grid2D::<T> {
-            elements,
-            width,
-            height
-        }
+/// Written with the grid's `Display`, one character per element
+impl<T: TryFrom<char> + Display> InputFormatter for Grid2DParser<T> {
+    fn format_input(output: &Self::Output) -> String {
+        output.to_string()
     }
 }
 
@@ -279,42 +542,87 @@ pub struct Grid2DWhitespaceParser<T: FromStr> {
     pub _element_type: PhantomData<T>
 }
 
-impl<T: FromStr> Grid2DWhitespaceParser<T> 
-    where <T as FromStr>::Err: Debug
-{
-    fn parse_line(line: &str) -> impl Iterator<Item = T> {
+impl<T: FromStr> Grid2DWhitespaceParser<T> {
+    fn parse_line(line: &str) -> Result<Vec<T>, ParseError> {
         line.split_whitespace()
-            .map(|token| T::from_str(token).expect("Parse item okay"))
+            .map(|token| parse_token(line, token))
+            .collect()
     }
 }
 
-impl<T: FromStr> InputParser for Grid2DWhitespaceParser<T> 
-    where <T as FromStr>::Err: Debug
-{
+impl<T: FromStr> InputParser for Grid2DWhitespaceParser<T> {
     type Output = Grid2D<T>;
 
-    fn parse_input(input: &str) -> Self::Output {
-        let grid_lines: Vec<Vec<T>> = input.lines()
-            .map(|line| Self::parse_line(line).collect())
-            .collect();
+    fn parse_input(input: &str) -> Result<Self::Output, ParseError> {
+        let grid_lines = input.lines()
+            .map(|line| Self::parse_line(line).map_err(|err| err.within(input, line)))
+            .collect::<Result<Vec<Vec<T>>, ParseError>>()?;
+
+        Grid2D::from_rows(input, grid_lines)
+    }
+}
+
+impl<T: FromStr + Display> InputFormatter for Grid2DWhitespaceParser<T> {
+    fn format_input(output: &Self::Output) -> String {
+        let mut formatted = String::new();
+        for y in 0..output.height {
+            let row: Vec<String> = (0..output.width)
+                .map(|x| output.get(Point2D::new(x, y)).unwrap().to_string())
+                .collect();
+            formatted.push_str(&row.join(" "));
+            formatted.push('\n');
+        }
+
+        formatted
+    }
+}
+
+impl<T> Grid2D<T> {
+    /// Flattens rows parsed from the lines of `input` into a grid, rejecting rows which differ in width from the first
+    pub fn from_rows(input: &str, grid_lines: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        let width = grid_lines.first()
+            .ok_or_else(|| ParseError::at_end(input, "at least one line of input"))?
+            .len();
+
+        let ragged_line = grid_lines.iter().position(|line| line.len() != width);
+        if let Some(line_index) = ragged_line {
+            let line = input.lines().nth(line_index).unwrap_or_default();
+            return Err(
+                ParseError::at(line, line, format!("row of width {width}"))
+                    .within(input, line)
+            );
+        }
 
         let height = grid_lines.len() as isize;
-        let width = grid_lines.get(0).expect("At least one line of input").len() as isize;
         let elements = grid_lines.into_iter().flatten().collect();
 
-        Self::Output {
+        Ok(Self {
             elements,
-            width,
+            width: width as isize,
             height
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{CardinalDirection, Direction, Grid2DParser, InputParser, OrdinalDirection, grid::Point2D};
-
-    const TEST_GRID: &'static str = include_str!("test_grid");
+    use crate::{
+        CardinalDirection, 
+        Direction, 
+        Grid, 
+        Grid2D, 
+        Grid2DParser, 
+        Grid2DWhitespaceParser, 
+        Input, 
+        SparseGrid2D, 
+        WrappingGrid2D, 
+        InputParser, 
+        OrdinalDirection, 
+        assert_round_trip, 
+        grid::{Point2D, Rect, Size2D}
+    };
+
+    const TEST_GRID: &str = include_str!("test_grid");
 
     #[derive(Clone, Copy, PartialEq, Debug)]
     enum TestCell {
@@ -346,7 +654,7 @@ mod test {
 
     #[test]
     fn test_parser_grid() {
-        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID);
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
         assert_eq!(grid.width, 10);
         assert_eq!(grid.height, 10);
         assert_eq!(grid.get(Point2D::new(0, 0)).cloned(), Some(TestCell::Off));
@@ -363,7 +671,7 @@ mod test {
 
     #[test]
     fn test_grid_bounds() {
-        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID);
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
         assert_eq!(grid.get(Point2D::new(-1, 0)), None);
         assert_eq!(grid.get(Point2D::new(10, 0)), None);
         assert_eq!(grid.get(Point2D::new(0, -1)), None);
@@ -379,7 +687,7 @@ mod test {
 
     #[test]
     fn test_grid_display() {
-        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID);
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
         let grid_display = format!("{grid}");
         
         // -1 for expected trailing new line on display
@@ -388,7 +696,7 @@ mod test {
 
     #[test]
     fn test_neighbours_cardinal() {
-        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID);
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
         let test_point = Point2D::new(1, 1);
         let mut neighbours = grid.point_neighbours::<CardinalDirection>(test_point);
         assert_eq!(neighbours.next(), Some(test_point + CardinalDirection::N.delta()));
@@ -400,7 +708,7 @@ mod test {
 
     #[test]
     fn test_neighbours_ordinal() {
-        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID);
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
         let test_point = Point2D::new(1, 1);
         let mut neighbours = grid.point_neighbours::<OrdinalDirection>(test_point);
         assert_eq!(neighbours.next(), Some(test_point + OrdinalDirection::N.delta()));
@@ -416,7 +724,7 @@ mod test {
 
     #[test]
     fn test_neighbours_boundary_top_left() {
-        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID);
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
         let test_point = Point2D::new(0, 0);
         let mut neighbours = grid.point_neighbours::<CardinalDirection>(test_point);
         assert_eq!(neighbours.next(), Some(test_point + CardinalDirection::E.delta()));
@@ -426,7 +734,7 @@ mod test {
 
     #[test]
     fn test_neighbours_boundary_bottom_right() {
-        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID);
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
         let test_point = Point2D::new(9, 9);
         let mut neighbours = grid.point_neighbours::<CardinalDirection>(test_point);
         assert_eq!(neighbours.next(), Some(test_point + CardinalDirection::N.delta()));
@@ -436,7 +744,7 @@ mod test {
 
     #[test]
     fn test_neighbours_boundary_edge() {
-        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID);
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
         let test_point = Point2D::new(5, 9);
         let mut neighbours = grid.point_neighbours::<CardinalDirection>(test_point);
         assert_eq!(neighbours.next(), Some(test_point + CardinalDirection::N.delta()));
@@ -444,4 +752,101 @@ mod test {
         assert_eq!(neighbours.next(), Some(test_point + CardinalDirection::W.delta()));
         assert_eq!(neighbours.next(), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parser_invalid_character() {
+        let error = Grid2DParser::<TestCell>::parse_input("..@\n.x@").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 2);
+        assert_eq!(error.expected, "valid grid character");
+    }
+
+    #[test]
+    fn test_parser_ragged_rows() {
+        let error = Grid2DParser::<TestCell>::parse_input("..@\n.@\n@@@").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.expected, "row of width 3");
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid2D::new();
+        assert_eq!(grid.rect(), Rect::zero());
+
+        grid.insert(Point2D::new(-2, 1), TestCell::On);
+        grid.insert(Point2D::new(1, -1), TestCell::On);
+        assert_eq!(grid.rect(), Rect::new(Point2D::new(-2, -1), Size2D::new(4, 3)));
+        assert_eq!(grid.to_string(), "...@\n....\n@...\n");
+
+        let neighbours: Vec<Point2D> = grid.point_neighbours::<CardinalDirection>(Point2D::new(-2, 0)).collect();
+        assert_eq!(neighbours.len(), 4);
+
+        let occupied: Vec<(&TestCell, Point2D)> = grid.element_neighbours::<CardinalDirection>(Point2D::new(-2, 0)).collect();
+        assert_eq!(occupied, vec![(&TestCell::On, Point2D::new(-2, 1))]);
+
+        *grid.get_mut(Point2D::new(1, -1)).unwrap() = TestCell::Off;
+        assert_eq!(grid.element_iter_filtered(&TestCell::On).count(), 1);
+        assert_eq!(grid.remove(Point2D::new(-2, 1)), Some(TestCell::On));
+        assert_eq!(grid.rect(), Rect::new(Point2D::new(1, -1), Size2D::new(1, 1)));
+        assert_eq!(grid.get(Point2D::new(1, -1)), Some(&TestCell::Off));
+    }
+
+    #[test]
+    fn test_sparse_from_dense() {
+        let dense = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
+        let sparse = SparseGrid2D::from(dense.clone());
+        assert_eq!(sparse.len(), 100);
+        assert_eq!(sparse.rect(), dense.rect());
+        assert_eq!(sparse.to_string(), dense.to_string());
+    }
+
+    #[test]
+    fn test_wrapped_get() {
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
+        assert_eq!(grid.wrap(Point2D::new(-1, 10)), Some(Point2D::new(9, 0)));
+        assert_eq!(grid.wrap(Point2D::new(23, -21)), Some(Point2D::new(3, 9)));
+        assert_eq!(grid.get_wrapped(Point2D::new(-10, 12)), grid.get(Point2D::new(0, 2)));
+
+        let empty = Grid2D::<TestCell> { elements: Vec::new(), width: 0, height: 0 };
+        assert_eq!(empty.get_wrapped(Point2D::new(1, 1)), None);
+    }
+
+    #[test]
+    fn test_neighbours_wrapped() {
+        let grid = Grid2DParser::<TestCell>::parse_input(TEST_GRID).unwrap();
+
+        let neighbours: Vec<Point2D> = grid.point_neighbours_wrapped::<CardinalDirection>(Point2D::new(0, 0)).collect();
+        assert_eq!(neighbours, vec![Point2D::new(0, 9), Point2D::new(1, 0), Point2D::new(0, 1), Point2D::new(9, 0)]);
+
+        let wrapping = WrappingGrid2D::new(grid.clone());
+        let corner = Point2D::new(9, 9);
+        let neighbours: Vec<Point2D> = wrapping.point_neighbours::<OrdinalDirection>(corner).collect();
+        assert_eq!(neighbours.len(), 8);
+        assert!(neighbours.contains(&Point2D::new(0, 0)));
+        assert!(neighbours.contains(&Point2D::new(8, 0)));
+
+        let elements = wrapping.element_neighbours::<OrdinalDirection>(corner).count();
+        assert_eq!(elements, 8);
+        assert_eq!(wrapping.get(Point2D::new(19, -1)), grid.get(corner));
+        assert!(wrapping.contains(Point2D::new(-3, 25)));
+        assert_eq!(wrapping.element_iter().count(), 100);
+    }
+
+    #[test]
+    fn test_grid_round_trip() {
+        assert_round_trip::<Grid2DParser<TestCell>>(&Grid2DParser::parse_input(TEST_GRID).unwrap());
+        assert_round_trip::<Grid2DWhitespaceParser<i32>>(&Grid2DWhitespaceParser::parse_input("1 -2\n30 4").unwrap());
+    }
+
+    #[test]
+    fn test_parser_normalized_input() {
+        let input = Input::from_string(String::from("\u{feff}..@\r\n.@.\r\n   \r\n")).normalized();
+        let grid = input.parse::<Grid2DParser<TestCell>>().unwrap();
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.height, 2);
+
+        let ragged = Input::from_str("..@\n.@\n").padded('.');
+        let grid = ragged.parse::<Grid2DParser<TestCell>>().unwrap();
+        assert_eq!(grid.get(Point2D::new(2, 1)).cloned(), Some(TestCell::Off));
+    }
+}
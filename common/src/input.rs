@@ -1,16 +1,242 @@
-use super::parser::InputParser;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use super::parser::BorrowingInputParser;
+use super::error::ParseError;
+
+/// Where the contents of an [`Input`] were loaded from
+#[derive(Clone, Debug)]
+pub enum InputSource {
+    Static,
+    File(PathBuf),
+    Stdin,
+    Memory,
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Static => write!(f, "static"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Memory => write!(f, "memory"),
+        }
+    }
+}
 
 #[derive(Clone)]
-pub struct Input(&'static str);
+pub struct Input {
+    contents: Cow<'static, str>,
+    source: InputSource,
+}
 
 impl Input {
     pub const fn from_str(str: &'static str) -> Self {
-        Self(str)
+        Self {
+            contents: Cow::Borrowed(str),
+            source: InputSource::Static,
+        }
+    }
+
+    pub fn from_string(contents: String) -> Self {
+        Self {
+            contents: Cow::Owned(contents),
+            source: InputSource::Memory,
+        }
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+
+        Ok(Self {
+            contents: Cow::Owned(contents),
+            source: InputSource::File(path.to_path_buf()),
+        })
+    }
+
+    pub fn from_stdin() -> std::io::Result<Self> {
+        let mut contents = String::new();
+        std::io::stdin().read_to_string(&mut contents)?;
+
+        Ok(Self {
+            contents: Cow::Owned(contents),
+            source: InputSource::Stdin,
+        })
+    }
+
+    /// Loads a file path, or stdin when given `-`
+    pub fn from_arg(arg: &str) -> std::io::Result<Self> {
+        match arg {
+            "-" => Self::from_stdin(),
+            path => Self::from_file(path),
+        }
+    }
+
+    /// Loads the input named by the first command line argument, falling back to `default`
+    pub fn from_args_or(default: Input) -> std::io::Result<Self> {
+        match std::env::args().nth(1) {
+            Some(arg) => Self::from_arg(&arg),
+            None => Ok(default),
+        }
+    }
+
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+
+    pub fn source(&self) -> &InputSource {
+        &self.source
+    }
+}
+
+impl Input {
+    /// Strips a UTF-8 byte order mark, converts CRLF and CR line endings to LF and
+    /// removes trailing lines which are blank or only whitespace
+    pub fn normalized(self) -> Self {
+        let contents = match self.contents {
+            Cow::Borrowed(contents) if !contents.contains('\r') => {
+                Cow::Borrowed(trim_trailing_blank_lines(strip_bom(contents)))
+            },
+            contents => {
+                let line_feeds = strip_bom(&contents).replace("\r\n", "\n").replace('\r', "\n");
+                Cow::Owned(trim_trailing_blank_lines(&line_feeds).to_string())
+            },
+        };
+
+        Self {
+            contents,
+            source: self.source,
+        }
+    }
+
+    /// Pads every line with `fill` to the width of the longest line, measured in characters
+    ///
+    /// Line endings, LF or CRLF, are kept as they are and not counted in the width.
+    pub fn padded(self, fill: char) -> Self {
+        let width = self.contents.split_inclusive('\n')
+            .map(|line| split_line_ending(line).0.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        let mut contents = String::with_capacity(self.contents.len());
+        for line in self.contents.split_inclusive('\n') {
+            let (line, ending) = split_line_ending(line);
+            contents.push_str(line);
+            contents.extend(std::iter::repeat_n(fill, width - line.chars().count()));
+            contents.push_str(ending);
+        }
+
+        Self {
+            contents: Cow::Owned(contents),
+            source: self.source,
+        }
     }
+
+    /// Rejects input whose lines are not all as wide as the first, measured in characters
+    pub fn check_rectangular(&self) -> Result<(), ParseError> {
+        let mut lines = self.contents.lines();
+        let width = lines.next().map_or(0, |line| line.chars().count());
+
+        for line in lines {
+            let line_width = line.chars().count();
+            if line_width == width {
+                continue;
+            }
+
+            let position = line.char_indices().nth(width).map_or(&line[line.len()..], |(index, _)| &line[index..]);
+            return Err(ParseError::at(
+                &self.contents,
+                position,
+                format!("row of {width} characters like the first, not {line_width}")
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn strip_bom(contents: &str) -> &str {
+    contents.strip_prefix('\u{feff}').unwrap_or(contents)
+}
+
+fn split_line_ending(line: &str) -> (&str, &str) {
+    let content = line.strip_suffix("\r\n")
+        .or_else(|| line.strip_suffix('\n'))
+        .unwrap_or(line);
+
+    line.split_at(content.len())
+}
+
+fn trim_trailing_blank_lines(contents: &str) -> &str {
+    let blank_length: usize = contents.split_inclusive('\n')
+        .rev()
+        .take_while(|line| line.trim().is_empty())
+        .map(str::len)
+        .sum();
+
+    &contents[..contents.len() - blank_length]
 }
 
 impl Input {
-    pub fn parse<T: InputParser>(self) -> T::Output {
-        T::parse_input(self.0)
+    pub fn parse<T: BorrowingInputParser>(&self) -> Result<T::Output<'_>, ParseError> {
+        T::parse_borrowed(&self.contents)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{FromStrParser, Input};
+
+    #[test]
+    fn test_owned_input_parse() {
+        let input = Input::from_string(String::from("1\n2\n3\n"));
+        assert_eq!(input.parse::<FromStrParser<u32>>().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_file_input_parse() {
+        let path = std::env::temp_dir().join("common_test_file_input_parse");
+        std::fs::write(&path, "4\n5\n").unwrap();
+
+        let input = Input::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(input.parse::<FromStrParser<u32>>().unwrap(), vec![4, 5]);
+        assert_eq!(input.source().to_string(), path.display().to_string());
+    }
+
+    #[test]
+    fn test_normalized_input() {
+        let input = Input::from_string(String::from("\u{feff}ab\r\ncd \r\n  \r\n\r\n")).normalized();
+        assert_eq!(input.contents(), "ab\ncd \n");
+
+        let input = Input::from_str("ab\ncd\n\n").normalized();
+        assert_eq!(input.contents(), "ab\ncd\n");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_ragged_rows() {
+        let input = Input::from_str("abc\nd\néf\n");
+        let error = input.check_rectangular().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "row of 3 characters like the first, not 1");
+
+        let padded = input.padded('.');
+        assert_eq!(padded.contents(), "abc\nd..\néf.\n");
+        assert!(padded.check_rectangular().is_ok());
+    }
+
+    #[test]
+    fn test_padded_crlf() {
+        let padded = Input::from_string(String::from("ab\r\nc\r\nde")).padded('.');
+        assert_eq!(padded.contents(), "ab\r\nc.\r\nde");
+    }
+
+    #[test]
+    fn test_missing_file_input() {
+        assert!(Input::from_file("does/not/exist").is_err());
+    }
+}
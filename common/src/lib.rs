@@ -1,17 +1,77 @@
+extern crate self as common;
+
+mod error;
+pub use error::ParseError;
+pub use error::{SolutionError, SolveError, SolveErrorKind};
+
 mod input;
 pub use input::Input;
+pub use input::InputSource;
 
 mod parser;
 pub use parser::InputParser;
+pub use parser::BorrowingInputParser;
 pub use parser::LineInputParser;
-pub use parser::DualSectionParser;
-pub use parser::InclusiveRangeListParser;
+pub use parser::{InputFormatter, LineInputFormatter, assert_round_trip};
+pub use parser::{DualSectionParser, SectionParser, SectionListParser, SectionDelimiter, BlankLine};
+pub use parser::{RangeListParser, InclusiveRangeListParser, RangeBound};
+pub use parser::{ListSeparator, LineSeparated, CommaSeparated, WhitespaceSeparated};
 pub use parser::FromStrParser;
-pub use parser::CSVParser;
+pub use parser::{parse_all, parse_token};
+
+mod delimited;
+pub use delimited::{DelimitedParser, DelimitedRecord, DelimitedField, FormatRecord, RecordFormat, Comma, CSVParser};
+pub use common_derive::DelimitedRecord;
+
+mod point;
+pub use point::{PointParser, Coordinates, PointLayout, Labelled};
+
+mod table;
+pub use table::{ColumnTableParser, TableCell, ColumnLayout, SeparatorColumns, FirstRowAnchor, LastRowAnchor};
+
+mod format;
+pub use format::{FormatSegment, split_format, furthest_error};
+pub use common_derive::LineParse;
+
+mod day_macro;
 
 mod solution;
 pub use solution::ProblemQuestion;
 pub use solution::Solution;
+pub use solution::FallibleSolution;
+pub use solution::StreamingSolution;
+pub use solution::Day;
+pub use solution::Part;
+pub use solution::PartVisitor;
+
+mod stream;
+pub use stream::LineStream;
+
+mod report;
+pub use report::{SolveReport, write_reports_csv, write_reports_json};
+
+mod cross_check;
+pub use cross_check::{CrossCheck, CrossCheckReport};
+
+mod cases;
+pub use cases::{ExampleCase, check_example_cases};
+
+mod answers;
+pub use answers::{AnswerCheck, AnswerRegistry, input_hash};
+
+mod benchmark;
+pub use benchmark::{BenchmarkConfig, BenchmarkReport, PhaseStats};
+
+mod memory;
+pub use memory::{MemoryStats, TrackingAllocator, is_tracking_memory, measure_memory};
+
+/// Problems and solutions shared by the unit tests of several modules
+#[cfg(test)]
+mod test_support;
+
+#[cfg(feature = "track-memory")]
+#[global_allocator]
+static GLOBAL_ALLOCATOR: TrackingAllocator = TrackingAllocator;
 
 mod grid;
 pub use grid::{
@@ -27,11 +87,17 @@ pub use grid::{
     OrdinalDirection,
 };
 pub use grid::{
+    Grid,
     Grid2D,
+    SparseGrid2D,
+    WrappingGrid2D,
     Grid2DParser,
     Grid2DWhitespaceParser
 };
 
+mod search;
+pub use search::{SearchResult, bfs, dijkstra, a_star, manhattan_distance};
+
 fn init_logger(is_test: bool) {
     env_logger::builder()
     .parse_env(env_logger::Env::default().default_filter_or("info"))
@@ -0,0 +1,177 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator, counting allocations and tracking live heap bytes
+///
+/// Installed as the global allocator by the `track-memory` feature, counters are
+/// shared between threads so concurrently running solves are measured together.
+pub struct TrackingAllocator;
+
+impl TrackingAllocator {
+    fn record_allocation(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+
+        let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn record_deallocation(size: usize) {
+        CURRENT_BYTES.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            Self::record_deallocation(layout.size());
+            Self::record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Heap usage over a measured section of code
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    pub allocations: usize,
+    /// Total bytes requested, including memory freed again during the section
+    pub bytes: usize,
+    /// Most heap bytes live at once during the section, beyond those already live when it started
+    pub peak: usize,
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            ByteSize(self.bytes),
+            ByteSize(self.peak)
+        )
+    }
+}
+
+struct ByteSize(usize);
+
+impl Display for ByteSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{}B", self.0);
+        }
+
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit + 1 < UNITS.len() {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{size:.2}{}", UNITS[unit])
+    }
+}
+
+/// Whether allocations are being counted, which requires [`TrackingAllocator`] to be the global allocator
+pub fn is_tracking_memory() -> bool {
+    ALLOCATIONS.load(Ordering::Relaxed) > 0
+}
+
+/// Runs `method`, measuring its heap usage when memory is being tracked
+///
+/// Measurements may be nested, the peak of an inner section also counts towards the outer one.
+/// The counters are process-global, so anything allocating on other threads meanwhile, such as
+/// tests running in parallel, is measured too.
+pub fn measure_memory<F, T>(method: F) -> (T, Option<MemoryStats>)
+    where F: FnOnce() -> T
+{
+    if !is_tracking_memory() {
+        return (method(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = CURRENT_BYTES.load(Ordering::Relaxed);
+    let outer_peak = PEAK_BYTES.swap(start, Ordering::Relaxed);
+
+    let result = method();
+
+    let peak = PEAK_BYTES.fetch_max(outer_peak, Ordering::Relaxed);
+    let stats = MemoryStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+        peak: peak.saturating_sub(start),
+    };
+
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ByteSize, MemoryStats, is_tracking_memory, measure_memory};
+
+    #[test]
+    fn test_byte_size() {
+        assert_eq!(ByteSize(512).to_string(), "512B");
+        assert_eq!(ByteSize(1536).to_string(), "1.50KiB");
+        assert_eq!(ByteSize(3 * 1024 * 1024).to_string(), "3.00MiB");
+    }
+
+    #[test]
+    fn test_measure_memory() {
+        let (buffer, stats) = measure_memory(|| vec![0u8; 1 << 20]);
+
+        if is_tracking_memory() {
+            let stats = stats.unwrap();
+            assert!(stats.allocations >= 1);
+            assert!(stats.bytes >= buffer.len());
+            assert!(stats.peak >= buffer.len());
+        } else {
+            assert_eq!(stats, None::<MemoryStats>);
+        }
+    }
+
+    #[test]
+    fn test_nested_measure_memory() {
+        let ((_, inner), outer) = measure_memory(|| {
+            let (buffer, _) = measure_memory(|| vec![0u8; 1 << 20]);
+            drop(buffer);
+            measure_memory(|| vec![0u8; 16])
+        });
+
+        if let (Some(inner), Some(outer)) = (inner, outer) {
+            assert!(inner.peak < 1 << 20);
+            assert!(outer.peak >= 1 << 20);
+        }
+    }
+}
@@ -1,95 +1,319 @@
-use std::{fmt::Debug, marker::PhantomData};
+use std::{any::type_name, fmt::{Debug, Display}, marker::PhantomData};
 use nom::{
     IResult, 
     Parser, 
+    branch::alt,
+    bytes::complete::tag,
     character::complete::{char, digit1}, 
-    combinator::map_res, 
-    sequence::separated_pair
+    combinator::{all_consuming, map_res, opt, recognize}
 };
 use std::{
-    num::ParseIntError, 
     ops::RangeInclusive, 
     str::FromStr
 };
 
+use crate::ParseError;
+
 pub trait InputParser {
     type Output;
 
-    fn parse_input(input: &str) -> Self::Output;
+    fn parse_input(input: &str) -> Result<Self::Output, ParseError>;
+}
+
+/// An [`InputParser`] whose output may borrow slices of the input, so tokens need not be copied
+///
+/// Every `InputParser` is also a `BorrowingInputParser` whose output borrows nothing.
+pub trait BorrowingInputParser {
+    type Output<'a>;
+
+    fn parse_borrowed(input: &str) -> Result<Self::Output<'_>, ParseError>;
+}
+
+impl<T: InputParser> BorrowingInputParser for T {
+    type Output<'a> = T::Output;
+
+    fn parse_borrowed(input: &str) -> Result<Self::Output<'_>, ParseError> {
+        T::parse_input(input)
+    }
 }
 
 pub trait LineInputParser {
     type LineOutput;
 
-    fn parse_line(line: &str) -> Self::LineOutput;
+    fn parse_line(line: &str) -> Result<Self::LineOutput, ParseError>;
 }
 
 impl<T: LineInputParser> InputParser for T {
     type Output = Vec<T::LineOutput>;
 
-    fn parse_input(input: &str) -> Self::Output {
-        input.lines().map(|line| Self::parse_line(line)).collect()
+    fn parse_input(input: &str) -> Result<Self::Output, ParseError> {
+        input.lines()
+            .map(|line| Self::parse_line(line).map_err(|err| err.within(input, line)))
+            .collect()
     }
 }
 
-pub struct CSVParser<T: FromStr> {
-    _element: PhantomData<T>,
+/// The inverse of an [`InputParser`], writing a parsed model back out in the format it was read from
+pub trait InputFormatter: InputParser {
+    fn format_input(output: &Self::Output) -> String;
 }
 
-impl<T: FromStr> LineInputParser for CSVParser<T> where <T as FromStr>::Err: Debug {
-    type LineOutput = Vec<T>;
+/// The inverse of a [`LineInputParser`], written without the line ending
+pub trait LineInputFormatter: LineInputParser {
+    fn format_line(output: &Self::LineOutput) -> String;
+}
 
-    fn parse_line(line: &str) -> Self::LineOutput {
-        line.split(',').map(|token| T::from_str(token).expect("Parse token ok")).collect()
+impl<T: LineInputFormatter> InputFormatter for T {
+    fn format_input(output: &Self::Output) -> String {
+        output.iter()
+            .map(|line| Self::format_line(line) + "\n")
+            .collect()
     }
 }
 
+/// Formats `output` with `P` then parses it back, panicking unless the result is equal to `output`
+pub fn assert_round_trip<P>(output: &P::Output)
+    where P: InputFormatter, P::Output: PartialEq + Debug
+{
+    let formatted = P::format_input(output);
+    let parsed = P::parse_input(&formatted)
+        .unwrap_or_else(|err| panic!("Formatted input could not be parsed back, {err}\n{formatted}"));
+
+    assert_eq!(&parsed, output, "Parsing the formatted input gave a different value\n{formatted}");
+}
+
+/// Runs a nom parser which must consume all of `input`
+pub fn parse_all<'a, P>(parser: P, input: &'a str, expected: &str) -> Result<P::Output, ParseError>
+    where P: Parser<&'a str, Error = nom::error::Error<&'a str>>
+{
+    all_consuming(parser)
+        .parse_complete(input)
+        .map(|(_, output)| output)
+        .map_err(|err| ParseError::from_nom(input, err, expected))
+}
+
+/// Parses a `token` sliced from `source` with `FromStr`
+pub fn parse_token<T: FromStr>(source: &str, token: &str) -> Result<T, ParseError> {
+    T::from_str(token).map_err(|_| ParseError::at(source, token, type_name::<T>()))
+}
+
+
+/// Separates the sections read by a [`SectionParser`] or [`SectionListParser`]
+pub trait SectionDelimiter {
+    const DELIMITER: &'static str;
+}
+
+/// Sections separated by an empty line
+pub struct BlankLine;
 
-fn split_parse<'a, P: InputParser>(input: &'a str, delimiter: &str) -> Option<(P::Output, &'a str)> {
-    let (parser_input, remainder) = input.split_once(delimiter)?;
-    let parser_output = P::parse_input(parser_input);
+impl SectionDelimiter for BlankLine {
+    const DELIMITER: &'static str = "\n\n";
+}
+
+/// Parses the section at the start of `remainder`, a slice of `source`, returning what follows its delimiter
+fn split_parse<'a, P: InputParser>(source: &str, remainder: &'a str, delimiter: &str) -> Result<(P::Output, &'a str), ParseError> {
+    let (parser_input, remainder) = remainder.split_once(delimiter)
+        .ok_or_else(|| ParseError::at_end(source, format!("section delimiter {delimiter:?}")))?;
+    let parser_output = P::parse_input(parser_input)
+        .map_err(|err| err.within(source, parser_input))?;
     
-    Some((parser_output, remainder))
+    Ok((parser_output, remainder))
 }
 
-pub struct DualSectionParser<A: InputParser, B: InputParser> {
-    _parser_a: PhantomData<A>,
-    _parser_b: PhantomData<B>
+/// Parses consecutive sections with a tuple of parsers, one per section
+pub struct SectionParser<Parsers, D: SectionDelimiter = BlankLine> {
+    _parsers: PhantomData<Parsers>,
+    _delimiter: PhantomData<D>
 }
 
-impl<A: InputParser, B: InputParser> InputParser for DualSectionParser<A, B> {
-    type Output = (A::Output, B::Output);
+pub type DualSectionParser<A, B> = SectionParser<(A, B)>;
 
-    fn parse_input(input: &str) -> Self::Output {
-        let (a_output, input) = split_parse::<A>(input, "\n\n")
-            .expect("Can split parsing sections okay");
-        let b_output = B::parse_input(input);
+macro_rules! impl_section_parser {
+    ($($parser:ident $output:ident),+; $last:ident) => {
+        impl<$($parser: InputParser,)+ $last: InputParser, D: SectionDelimiter> InputParser for SectionParser<($($parser,)+ $last), D> {
+            type Output = ($($parser::Output,)+ $last::Output);
 
-        (a_output, b_output)
-    }
+            fn parse_input(input: &str) -> Result<Self::Output, ParseError> {
+                let remainder = input;
+                $(
+                    let ($output, remainder) = split_parse::<$parser>(input, remainder, D::DELIMITER)?;
+                )+
+                let last = $last::parse_input(remainder)
+                    .map_err(|err| err.within(input, remainder))?;
+
+                Ok(($($output,)+ last))
+            }
+        }
+    };
+}
+
+/// Joins formatted sections with `delimiter`, dropping the line ending each would otherwise end with
+fn join_sections(sections: Vec<String>, delimiter: &str) -> String {
+    let last = sections.len().saturating_sub(1);
+    sections.into_iter()
+        .enumerate()
+        .map(|(index, section)| {
+            if index == last {
+                section
+            } else {
+                format!("{}{delimiter}", section.strip_suffix('\n').unwrap_or(&section))
+            }
+        })
+        .collect()
+}
+
+macro_rules! impl_section_formatter {
+    ($($parser:ident $output:tt),+) => {
+        impl<$($parser: InputFormatter,)+ D: SectionDelimiter> InputFormatter for SectionParser<($($parser,)+), D> {
+            fn format_input(output: &Self::Output) -> String {
+                join_sections(vec![$($parser::format_input(&output.$output),)+], D::DELIMITER)
+            }
+        }
+    };
+}
+
+impl_section_formatter!(A 0, B 1);
+impl_section_formatter!(A 0, B 1, C 2);
+impl_section_formatter!(A 0, B 1, C 2, E 3);
+impl_section_formatter!(A 0, B 1, C 2, E 3, F 4);
+impl_section_formatter!(A 0, B 1, C 2, E 3, F 4, G 5);
+
+impl_section_parser!(A a; B);
+impl_section_parser!(A a, B b; C);
+impl_section_parser!(A a, B b, C c; E);
+impl_section_parser!(A a, B b, C c, E e; F);
+impl_section_parser!(A a, B b, C c, E e, F f; G);
+
+/// Parses any number of sections which share one parser, ignoring a trailing empty section
+pub struct SectionListParser<P: InputParser, D: SectionDelimiter = BlankLine> {
+    _parser: PhantomData<P>,
+    _delimiter: PhantomData<D>
 }
 
-pub struct InclusiveRangeListParser;
+impl<P: InputParser, D: SectionDelimiter> InputParser for SectionListParser<P, D> {
+    type Output = Vec<P::Output>;
+
+    fn parse_input(input: &str) -> Result<Self::Output, ParseError> {
+        let mut sections: Vec<&str> = input.split(D::DELIMITER).collect();
+        if sections.len() > 1 && sections.last().is_some_and(|section| section.trim().is_empty()) {
+            sections.pop();
+        }
 
-impl LineInputParser for InclusiveRangeListParser {
-    type LineOutput = RangeInclusive<u64>;
+        sections.into_iter()
+            .map(|section| P::parse_input(section).map_err(|err| err.within(input, section)))
+            .collect()
+    }
+}
 
-    fn parse_line(line: &str) -> Self::LineOutput {
-        Self::parse_range(line).expect("Can parse input ok").1
+impl<P: InputFormatter, D: SectionDelimiter> InputFormatter for SectionListParser<P, D> {
+    fn format_input(output: &Self::Output) -> String {
+        join_sections(output.iter().map(P::format_input).collect(), D::DELIMITER)
     }
 }
 
-impl InclusiveRangeListParser {
-    fn parse_range(input: &str) -> IResult<&str, RangeInclusive<u64>> {
-        map_res(
-            separated_pair(digit1, char('-'), digit1),
-            |(start_str, end_str)| {
-                let start = u64::from_str(start_str)?;
-                let end = u64::from_str(end_str)?;
+/// Separates the items of a list read by a [`RangeListParser`]
+pub trait ListSeparator {
+    const SEPARATOR: char;
+}
+
+/// One item per line
+pub struct LineSeparated;
+
+/// Items separated by commas, with optional spaces around each, as in `1,-2,3`
+pub struct CommaSeparated;
+
+/// Items separated by any amount of whitespace, as in `1 -2 3`
+pub struct WhitespaceSeparated;
+
+impl ListSeparator for LineSeparated {
+    const SEPARATOR: char = '\n';
+}
+
+impl ListSeparator for CommaSeparated {
+    const SEPARATOR: char = ',';
+}
+
+/// An integer which can bound a range, exclusive ends are converted with its predecessor
+pub trait RangeBound: FromStr + Copy + PartialOrd {
+    fn predecessor(self) -> Option<Self>;
+}
 
-                Result::<RangeInclusive<u64>, ParseIntError>::Ok(RangeInclusive::new(start, end))
+macro_rules! impl_range_bound {
+    ($($integer:ty),+) => {
+        $(
+            impl RangeBound for $integer {
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
             }
-        ).parse(input)
+        )+
+    };
+}
+
+impl_range_bound!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Parses a list of ranges written `a-b`, `a..=b` or `a..b`, bounds may be negative
+///
+/// Every range is returned inclusive, so `a..b` becomes `a..=b-1`. A range must hold at least one
+/// value, so reversed ranges such as `5-3` and empty ones such as `3..3` are rejected whatever the bound type.
+pub struct RangeListParser<T: RangeBound = u64, S: ListSeparator = LineSeparated> {
+    _bound: PhantomData<T>,
+    _separator: PhantomData<S>,
+}
+
+pub type InclusiveRangeListParser = RangeListParser<u64>;
+
+impl<T: RangeBound, S: ListSeparator> InputParser for RangeListParser<T, S> {
+    type Output = Vec<RangeInclusive<T>>;
+
+    fn parse_input(input: &str) -> Result<Self::Output, ParseError> {
+        let list = input.trim_end();
+        if list.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        list.split(S::SEPARATOR)
+            .map(str::trim)
+            .map(|token| Self::parse_range(token).map_err(|err| err.within(input, token)))
+            .collect()
+    }
+}
+
+impl<T: RangeBound + Display, S: ListSeparator> InputFormatter for RangeListParser<T, S> {
+    fn format_input(output: &Self::Output) -> String {
+        let ranges: Vec<String> = output.iter()
+            .map(|range| format!("{}-{}", range.start(), range.end()))
+            .collect();
+
+        ranges.join(&S::SEPARATOR.to_string()) + "\n"
+    }
+}
+
+impl<T: RangeBound, S: ListSeparator> RangeListParser<T, S> {
+    /// Parses a single range such as `-3..=5`
+    pub fn parse_range(token: &str) -> Result<RangeInclusive<T>, ParseError> {
+        let (start, inclusive, end) = parse_all(
+            (Self::parse_bound, alt((tag("..="), tag(".."), tag("-"))), Self::parse_bound),
+            token,
+            "range such as a-b, a..b or a..=b"
+        )?;
+
+        let is_empty = if inclusive == ".." { start >= end } else { start > end };
+        if is_empty {
+            return Err(ParseError::at(token, token, "range with its start before its end"));
+        }
+
+        if inclusive != ".." {
+            return Ok(start..=end);
+        }
+
+        end.predecessor()
+            .map(|end| start..=end)
+            .ok_or_else(|| ParseError::at(token, token, format!("range ending after {}", type_name::<T>())))
+    }
+
+    fn parse_bound(input: &str) -> IResult<&str, T> {
+        map_res(recognize((opt(char('-')), digit1)), T::from_str).parse(input)
     }
 }
 
@@ -97,12 +321,157 @@ pub struct FromStrParser<T: FromStr> {
     _element: PhantomData<T>
 }
 
-impl<T: FromStr> LineInputParser for FromStrParser<T> 
-  where <T as FromStr>::Err: Debug
-{
+impl<T: FromStr> LineInputParser for FromStrParser<T> {
     type LineOutput = T;
 
-    fn parse_line(line: &str) -> Self::LineOutput {
-        T::from_str(line).expect("FromStr always succeeds")
+    fn parse_line(line: &str) -> Result<Self::LineOutput, ParseError> {
+        parse_token(line, line)
+    }
+}
+
+impl<T: FromStr + Display> LineInputFormatter for FromStrParser<T> {
+    fn format_line(output: &Self::LineOutput) -> String {
+        output.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        BorrowingInputParser, 
+        CSVParser, 
+        DualSectionParser, 
+        FromStrParser, 
+        InclusiveRangeListParser, 
+        CommaSeparated, 
+        InputParser, 
+        RangeListParser, 
+        SectionDelimiter, 
+        SectionListParser, 
+        SectionParser, 
+        assert_round_trip
+    };
+
+    struct WordParser;
+    impl BorrowingInputParser for WordParser {
+        type Output<'a> = Vec<&'a str>;
+
+        fn parse_borrowed(input: &str) -> Result<Self::Output<'_>, crate::ParseError> {
+            Ok(input.split_whitespace().collect())
+        }
+    }
+
+    #[test]
+    fn test_borrowed_output() {
+        let input = "alpha beta
+gamma";
+        let words = WordParser::parse_borrowed(input).unwrap();
+        assert_eq!(words, vec!["alpha", "beta", "gamma"]);
+        assert!(std::ptr::eq(words[2].as_ptr(), input[11..].as_ptr()));
+
+        let owned = FromStrParser::<u32>::parse_borrowed("1\n2").unwrap();
+        assert_eq!(owned, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_csv_error_position() {
+        let error = CSVParser::<u32>::parse_input("1,2,3\n4,x,6").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 3);
+        assert_eq!(error.snippet, "x");
+        assert_eq!(error.expected, "u32");
+    }
+
+    #[test]
+    fn test_range_trailing_character() {
+        let error = InclusiveRangeListParser::parse_input("3-5\n10-14x").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 6);
+        assert_eq!(error.expected, "end of line");
+    }
+
+    #[test]
+    fn test_range_syntaxes() {
+        let ranges = RangeListParser::<i32>::parse_input("-5--2\n-1..=3\n4..7\n").unwrap();
+        assert_eq!(ranges, vec![-5..=-2, -1..=3, 4..=6]);
+
+        let ranges = RangeListParser::<u8, CommaSeparated>::parse_input("1-2, 3..4\n").unwrap();
+        assert_eq!(ranges, vec![1..=2, 3..=3]);
+
+        let error = RangeListParser::<u8, CommaSeparated>::parse_input("1-2,-3..4").unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(error.expected, "range such as a-b, a..b or a..=b");
+
+        assert!(RangeListParser::<u8>::parse_input("3..0").is_err());
+    }
+
+    #[test]
+    fn test_empty_ranges() {
+        for range in ["5-3", "5..=3", "5..3", "3..3", "0..0"] {
+            let error = RangeListParser::<u8>::parse_input(range).unwrap_err();
+            assert_eq!(error.expected, "range with its start before its end", "{range}");
+
+            let error = RangeListParser::<i32>::parse_input(range).unwrap_err();
+            assert_eq!(error.expected, "range with its start before its end", "{range}");
+        }
+
+        let error = RangeListParser::<i32>::parse_input("-2..-5").unwrap_err();
+        assert_eq!(error.expected, "range with its start before its end");
+
+        assert_eq!(RangeListParser::<u8>::parse_input("3..=3").unwrap(), vec![3..=3]);
+        assert_eq!(RangeListParser::<i32>::parse_input("-2..-1").unwrap(), vec![-2..=-2]);
+    }
+
+    #[test]
+    fn test_section_error_position() {
+        type Parser = DualSectionParser<FromStrParser<u32>, FromStrParser<u32>>;
+
+        let error = Parser::parse_input("1\n2\n\n3\n-4").unwrap_err();
+        assert_eq!(error.line, 5);
+        assert_eq!(error.column, 1);
+
+        let error = Parser::parse_input("1\n2").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 2);
+    }
+
+    #[test]
+    fn test_triple_section() {
+        type Parser = SectionParser<(FromStrParser<u32>, CSVParser<u32>, FromStrParser<i8>)>;
+
+        let (first, second, third) = Parser::parse_input("1\n2\n\n3,4\n\n-5\n").unwrap();
+        assert_eq!(first, vec![1, 2]);
+        assert_eq!(second, vec![vec![3, 4]]);
+        assert_eq!(third, vec![-5]);
+
+        let error = Parser::parse_input("1\n\n2,x\n\n3").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 3);
+    }
+
+    struct Dashes;
+    impl SectionDelimiter for Dashes {
+        const DELIMITER: &'static str = "\n---\n";
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_format_round_trip() {
+        type Sections = SectionParser<(FromStrParser<u32>, RangeListParser<i8, CommaSeparated>, CSVParser<u32>)>;
+        assert_round_trip::<Sections>(&(vec![1, 2], vec![-3..=4, 5..=5], vec![vec![6, 7]]));
+        assert_round_trip::<SectionListParser<FromStrParser<u32>, Dashes>>(&vec![vec![1], vec![2, 3]]);
+        assert_round_trip::<RangeListParser<i64>>(&vec![-10..=-5, 0..=3]);
+    }
+
+    #[test]
+    fn test_section_list() {
+        let sections = SectionListParser::<FromStrParser<u32>>::parse_input("1\n2\n\n3\n\n4\n\n").unwrap();
+        assert_eq!(sections, vec![vec![1, 2], vec![3], vec![4]]);
+
+        let sections = SectionListParser::<FromStrParser<u32>, Dashes>::parse_input("1\n---\n2\n3").unwrap();
+        assert_eq!(sections, vec![vec![1], vec![2, 3]]);
+
+        let error = SectionListParser::<FromStrParser<u32>, Dashes>::parse_input("1\n---\n2\n---\nx").unwrap_err();
+        assert_eq!(error.line, 5);
+        assert_eq!(error.column, 1);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
+
+use crate::{CommaSeparated, LineInputFormatter, LineInputParser, ParseError, WhitespaceSeparated, parse_token};
+
+const AXIS_LABELS: [&str; 4] = ["x", "y", "z", "w"];
+
+/// A point which can be built from its coordinates, listed in axis order
+pub trait Coordinates: Sized {
+    type Scalar: FromStr;
+    const DIMENSIONS: usize;
+
+    /// Builds the point from exactly `DIMENSIONS` values
+    fn from_coordinates(values: Vec<Self::Scalar>) -> Self;
+
+    fn coordinates(&self) -> Vec<&Self::Scalar>;
+}
+
+impl<T: FromStr, U> Coordinates for euclid::Point2D<T, U> {
+    type Scalar = T;
+    const DIMENSIONS: usize = 2;
+
+    fn from_coordinates(values: Vec<T>) -> Self {
+        let [x, y] = <[T; 2]>::from_coordinates(values);
+        Self::new(x, y)
+    }
+
+    fn coordinates(&self) -> Vec<&T> {
+        vec![&self.x, &self.y]
+    }
+}
+
+impl<T: FromStr, U> Coordinates for euclid::Point3D<T, U> {
+    type Scalar = T;
+    const DIMENSIONS: usize = 3;
+
+    fn from_coordinates(values: Vec<T>) -> Self {
+        let [x, y, z] = <[T; 3]>::from_coordinates(values);
+        Self::new(x, y, z)
+    }
+
+    fn coordinates(&self) -> Vec<&T> {
+        vec![&self.x, &self.y, &self.z]
+    }
+}
+
+impl<T: FromStr, const N: usize> Coordinates for [T; N] {
+    type Scalar = T;
+    const DIMENSIONS: usize = N;
+
+    fn from_coordinates(values: Vec<T>) -> Self {
+        values.try_into().unwrap_or_else(|_| panic!("Expected {N} coordinates"))
+    }
+
+    fn coordinates(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+/// How the coordinates of a point are written on its line
+pub trait PointLayout {
+    /// Splits `line` into one token per axis, each a slice of `line`
+    fn split(line: &str, dimensions: usize) -> Result<Vec<&str>, ParseError>;
+
+    /// Writes formatted coordinates, in axis order, as a line
+    fn join(values: Vec<String>) -> String;
+}
+
+impl PointLayout for CommaSeparated {
+    fn split(line: &str, dimensions: usize) -> Result<Vec<&str>, ParseError> {
+        let tokens: Vec<&str> = line.split(',').map(str::trim).collect();
+        check_count(line, &tokens, dimensions, "comma separated coordinates")?;
+        Ok(tokens)
+    }
+
+    fn join(values: Vec<String>) -> String {
+        values.join(",")
+    }
+}
+
+impl PointLayout for WhitespaceSeparated {
+    fn split(line: &str, dimensions: usize) -> Result<Vec<&str>, ParseError> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        check_count(line, &tokens, dimensions, "whitespace separated coordinates")?;
+        Ok(tokens)
+    }
+
+    fn join(values: Vec<String>) -> String {
+        values.join(" ")
+    }
+}
+
+/// Comma separated coordinates labelled by axis in order, as in `x=1, y=-2, z=3`
+///
+/// Axes are labelled `x`, `y`, `z` then `w`, so at most four are supported.
+pub struct Labelled;
+
+impl PointLayout for Labelled {
+    fn split(line: &str, dimensions: usize) -> Result<Vec<&str>, ParseError> {
+        if dimensions > AXIS_LABELS.len() {
+            return Err(ParseError::at(line, line, format!("point of at most {} labelled axes, not {dimensions}", AXIS_LABELS.len())));
+        }
+
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        check_count(line, &fields, dimensions, "labelled coordinates")?;
+
+        fields.into_iter()
+            .zip(AXIS_LABELS)
+            .map(|(field, label)| {
+                field.strip_prefix(label)
+                    .map(str::trim_start)
+                    .and_then(|rest| rest.strip_prefix('='))
+                    .map(str::trim_start)
+                    .ok_or_else(|| ParseError::at(line, field, format!("\"{label}=\"")))
+            })
+            .collect()
+    }
+
+    fn join(values: Vec<String>) -> String {
+        let labelled: Vec<String> = values.into_iter()
+            .zip(AXIS_LABELS)
+            .map(|(value, label)| format!("{label}={value}"))
+            .collect();
+
+        labelled.join(", ")
+    }
+}
+
+fn check_count(line: &str, tokens: &[&str], dimensions: usize, description: &str) -> Result<(), ParseError> {
+    match tokens.get(dimensions) {
+        Some(extra) => Err(ParseError::at(line, extra, format!("end of line after {dimensions} {description}"))),
+        None if tokens.len() < dimensions => Err(ParseError::at_end(line, format!("{dimensions} {description}"))),
+        None => Ok(()),
+    }
+}
+
+/// Parses one point per line, such as `euclid::default::Point3D<i64>` or `[i32; 4]`
+pub struct PointParser<P: Coordinates, L: PointLayout = CommaSeparated> {
+    _point: PhantomData<P>,
+    _layout: PhantomData<L>,
+}
+
+impl<P: Coordinates, L: PointLayout> LineInputParser for PointParser<P, L> {
+    type LineOutput = P;
+
+    fn parse_line(line: &str) -> Result<Self::LineOutput, ParseError> {
+        let values = L::split(line, P::DIMENSIONS)?
+            .into_iter()
+            .map(|token| parse_token(line, token))
+            .collect::<Result<Vec<P::Scalar>, ParseError>>()?;
+
+        Ok(P::from_coordinates(values))
+    }
+}
+
+impl<P: Coordinates, L: PointLayout> LineInputFormatter for PointParser<P, L>
+    where P::Scalar: Display
+{
+    fn format_line(output: &Self::LineOutput) -> String {
+        L::join(output.coordinates().into_iter().map(ToString::to_string).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use euclid::default::{Point2D, Point3D};
+
+    use crate::{InputParser, LineInputFormatter, LineInputParser, WhitespaceSeparated, assert_round_trip};
+    use super::{Labelled, PointParser};
+
+    #[test]
+    fn test_comma_points() {
+        let points = PointParser::<Point3D<i64>>::parse_input("1,2,3\n-4, 5, -6").unwrap();
+        assert_eq!(points, vec![Point3D::new(1, 2, 3), Point3D::new(-4, 5, -6)]);
+
+        let error = PointParser::<Point2D<i64>>::parse_input("1,2\n3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = PointParser::<Point2D<u8>>::parse_line("1,-2").unwrap_err();
+        assert_eq!(error.column, 3);
+        assert_eq!(error.expected, "u8");
+    }
+
+    #[test]
+    fn test_whitespace_arrays() {
+        let point = PointParser::<[i32; 4], WhitespaceSeparated>::parse_line("  1 -2\t3  4").unwrap();
+        assert_eq!(point, [1, -2, 3, 4]);
+
+        let error = PointParser::<[i32; 2], WhitespaceSeparated>::parse_line("1 2 3").unwrap_err();
+        assert_eq!(error.column, 5);
+    }
+
+    #[test]
+    fn test_labelled_points() {
+        let point = PointParser::<Point2D<i32>, Labelled>::parse_line("x=-3, y = 7").unwrap();
+        assert_eq!(point, Point2D::new(-3, 7));
+
+        let error = PointParser::<Point2D<i32>, Labelled>::parse_line("x=1,z=2").unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(error.expected, "\"y=\"");
+
+        let error = PointParser::<[i32; 5], Labelled>::parse_line("x=1, y=2, z=3, w=4, v=5").unwrap_err();
+        assert_eq!(error.column, 1);
+        assert_eq!(error.expected, "point of at most 4 labelled axes, not 5");
+    }
+
+    #[test]
+    fn test_format_points() {
+        let formatted = PointParser::<Point3D<i32>, Labelled>::format_line(&Point3D::new(1, -2, 3));
+        assert_eq!(formatted, "x=1, y=-2, z=3");
+
+        assert_round_trip::<PointParser<Point2D<i64>>>(&vec![Point2D::new(-1, 2), Point2D::new(3, 4)]);
+        assert_round_trip::<PointParser<[u8; 3], WhitespaceSeparated>>(&vec![[1, 2, 3]]);
+    }
+}
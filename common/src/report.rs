@@ -0,0 +1,193 @@
+use std::io::Write;
+use std::time::Duration;
+
+use crate::{MemoryStats, Part};
+
+/// The outcome of solving a `ProblemQuestion` once, with the time spent in each phase
+#[derive(Clone, Debug)]
+pub struct SolveReport {
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub problem: &'static str,
+    pub solution: &'static str,
+    pub input: String,
+    pub output: String,
+    pub parse: Duration,
+    pub answer: Duration,
+    pub total: Duration,
+    /// Heap usage of each phase, only measured when memory is being tracked
+    pub parse_memory: Option<MemoryStats>,
+    pub answer_memory: Option<MemoryStats>,
+}
+
+const CSV_HEADER: &str = "day,part,problem,solution,input,output,parse_ns,answer_ns,total_ns,\
+    parse_allocations,parse_bytes,parse_peak_bytes,answer_allocations,answer_bytes,answer_peak_bytes";
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+
+    for chr in value.chars() {
+        match chr {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            chr if chr.is_control() => output.push_str(&format!("\\u{:04x}", chr as u32)),
+            chr => output.push(chr),
+        }
+    }
+
+    output.push('"');
+    output
+}
+
+fn json_optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| String::from("null"), |value| value.to_string())
+}
+
+fn memory_fields(memory: Option<MemoryStats>) -> [Option<usize>; 3] {
+    match memory {
+        Some(memory) => [Some(memory.allocations), Some(memory.bytes), Some(memory.peak)],
+        None => [None; 3],
+    }
+}
+
+/// Writes one CSV row per report, preceded by a header row
+pub fn write_reports_csv<W: Write>(reports: &[SolveReport], mut writer: W) -> std::io::Result<()> {
+    writeln!(writer, "{CSV_HEADER}")?;
+
+    for report in reports {
+        write!(
+            writer,
+            "{},{},{},{},{},{},{},{},{}",
+            report.day.map(|day| day.to_string()).unwrap_or_default(),
+            report.part.map(|part| part.to_string()).unwrap_or_default(),
+            csv_field(report.problem),
+            csv_field(report.solution),
+            csv_field(&report.input),
+            csv_field(&report.output),
+            report.parse.as_nanos(),
+            report.answer.as_nanos(),
+            report.total.as_nanos(),
+        )?;
+
+        let memory = memory_fields(report.parse_memory).into_iter().chain(memory_fields(report.answer_memory));
+        for field in memory {
+            write!(writer, ",{}", field.map(|field| field.to_string()).unwrap_or_default())?;
+        }
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+/// Writes reports as a JSON array of objects
+pub fn write_reports_json<W: Write>(reports: &[SolveReport], mut writer: W) -> std::io::Result<()> {
+    writeln!(writer, "[")?;
+
+    for (index, report) in reports.iter().enumerate() {
+        let separator = if index + 1 < reports.len() { "," } else { "" };
+
+        write!(
+            writer,
+            "  {{\"day\": {}, \"part\": {}, \"problem\": {}, \"solution\": {}, \"input\": {}, \"output\": {}, \"parse_ns\": {}, \"answer_ns\": {}, \"total_ns\": {}",
+            json_optional(report.day),
+            json_optional(report.part),
+            json_string(report.problem),
+            json_string(report.solution),
+            json_string(&report.input),
+            json_string(&report.output),
+            report.parse.as_nanos(),
+            report.answer.as_nanos(),
+            report.total.as_nanos(),
+        )?;
+
+        for (phase, memory) in [("parse", report.parse_memory), ("answer", report.answer_memory)] {
+            let [allocations, bytes, peak] = memory_fields(memory);
+            write!(
+                writer,
+                ", \"{phase}_allocations\": {}, \"{phase}_bytes\": {}, \"{phase}_peak_bytes\": {}",
+                json_optional(allocations),
+                json_optional(bytes),
+                json_optional(peak),
+            )?;
+        }
+        writeln!(writer, "}}{separator}")?;
+    }
+
+    writeln!(writer, "]")
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{Input, MemoryStats, Part, ProblemQuestion};
+    use crate::test_support::{SumProblem, SumSolution};
+    use super::{SolveReport, write_reports_csv, write_reports_json};
+
+    fn test_report() -> SolveReport {
+        SolveReport {
+            day: Some(3),
+            part: Some(Part::Two),
+            problem: "day_03::ProblemTwo",
+            solution: "day_03::DayThreeSolution",
+            input: String::from("say \"hi\", please"),
+            output: String::from("42"),
+            parse: Duration::from_nanos(1500),
+            answer: Duration::from_nanos(2500),
+            total: Duration::from_nanos(4100),
+            parse_memory: Some(MemoryStats {
+                allocations: 4,
+                bytes: 256,
+                peak: 128,
+            }),
+            answer_memory: None,
+        }
+    }
+
+    #[test]
+    fn test_solve_with_report() {
+        let (output, report) = SumProblem::solve_with_report::<SumSolution>(Input::from_str("1\n2\n3")).unwrap();
+
+        assert_eq!(output, 6);
+        assert_eq!(report.output, "6");
+        assert_eq!(report.input, "static");
+        assert!(report.problem.ends_with("SumProblem"));
+        assert!(report.total >= report.parse + report.answer);
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let mut output = Vec::new();
+        write_reports_csv(&[test_report()], &mut output).unwrap();
+
+        let expected = "day,part,problem,solution,input,output,parse_ns,answer_ns,total_ns,\
+            parse_allocations,parse_bytes,parse_peak_bytes,answer_allocations,answer_bytes,answer_peak_bytes\n\
+            3,2,day_03::ProblemTwo,day_03::DayThreeSolution,\"say \"\"hi\"\", please\",42,1500,2500,4100,4,256,128,,,\n";
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_export_json() {
+        let mut output = Vec::new();
+        write_reports_json(&[test_report()], &mut output).unwrap();
+
+        let expected = "[\n  {\"day\": 3, \"part\": 2, \"problem\": \"day_03::ProblemTwo\", \
+            \"solution\": \"day_03::DayThreeSolution\", \"input\": \"say \\\"hi\\\", please\", \
+            \"output\": \"42\", \"parse_ns\": 1500, \"answer_ns\": 2500, \"total_ns\": 4100, \
+            \"parse_allocations\": 4, \"parse_bytes\": 256, \"parse_peak_bytes\": 128, \
+            \"answer_allocations\": null, \"answer_bytes\": null, \"answer_peak_bytes\": null}\n]\n";
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+}
@@ -0,0 +1,247 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::{Direction, Grid, Point2D};
+
+/// What a search over a [`Grid`] found: the cost of reaching each point and the point each was reached from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult {
+    pub start: Point2D,
+    pub distances: HashMap<Point2D, u64>,
+    pub predecessors: HashMap<Point2D, Point2D>,
+}
+
+impl SearchResult {
+    fn new(start: Point2D) -> Self {
+        Self {
+            start,
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, point: Point2D) -> Option<u64> {
+        self.distances.get(&point).copied()
+    }
+
+    /// The points stepped through from the start to `target`, both included, `None` when `target` was not reached
+    pub fn path_to(&self, target: Point2D) -> Option<Vec<Point2D>> {
+        if !self.distances.contains_key(&target) {
+            return None;
+        }
+
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(previous) = self.predecessors.get(&current) {
+            path.push(*previous);
+            current = *previous;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// The number of cardinal steps between two points, a heuristic for [`a_star`] on unit cost grids
+pub fn manhattan_distance(a: Point2D, b: Point2D) -> u64 {
+    (a.x.abs_diff(b.x) + a.y.abs_diff(b.y)) as u64
+}
+
+/// Breadth first search from `start`, stepping in `D`'s directions onto elements which are `passable`
+///
+/// Every step costs 1, so the distances are step counts.
+pub fn bfs<D: Direction, G: Grid>(
+    grid: &G,
+    start: Point2D,
+    passable: impl Fn(&G::Element) -> bool,
+) -> SearchResult {
+    let mut result = SearchResult::new(start);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(point) = queue.pop_front() {
+        let distance = result.distances[&point];
+        for (element, neighbour) in grid.element_neighbours::<D>(point) {
+            if result.distances.contains_key(&neighbour) || !passable(element) {
+                continue;
+            }
+
+            result.distances.insert(neighbour, distance + 1);
+            result.predecessors.insert(neighbour, point);
+            queue.push_back(neighbour);
+        }
+    }
+
+    result
+}
+
+/// Cheapest paths from `start` to every reachable point
+///
+/// `cost` gives the price of stepping from one element onto a neighbouring one which is `passable`.
+pub fn dijkstra<D: Direction, G: Grid>(
+    grid: &G,
+    start: Point2D,
+    passable: impl Fn(&G::Element) -> bool,
+    cost: impl Fn(&G::Element, &G::Element) -> u64,
+) -> SearchResult {
+    best_first::<D, G>(grid, start, None, passable, cost, |_| 0)
+}
+
+/// Cheapest path from `start` to `goal`, exploring points closest to the goal by `heuristic` first
+///
+/// The heuristic must never overestimate the remaining cost, e.g. [`manhattan_distance`] for unit
+/// steps in cardinal directions. The search stops once `goal` is reached, so only the distances
+/// and paths of `goal` and the points on its path are guaranteed to be the cheapest.
+pub fn a_star<D: Direction, G: Grid>(
+    grid: &G,
+    start: Point2D,
+    goal: Point2D,
+    passable: impl Fn(&G::Element) -> bool,
+    cost: impl Fn(&G::Element, &G::Element) -> u64,
+    heuristic: impl Fn(Point2D) -> u64,
+) -> SearchResult {
+    best_first::<D, G>(grid, start, Some(goal), passable, cost, heuristic)
+}
+
+fn best_first<D: Direction, G: Grid>(
+    grid: &G,
+    start: Point2D,
+    goal: Option<Point2D>,
+    passable: impl Fn(&G::Element) -> bool,
+    cost: impl Fn(&G::Element, &G::Element) -> u64,
+    heuristic: impl Fn(Point2D) -> u64,
+) -> SearchResult {
+    let mut result = SearchResult::new(start);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), 0, start.x, start.y))]);
+
+    while let Some(Reverse((_, distance, x, y))) = queue.pop() {
+        let point = Point2D::new(x, y);
+        if goal == Some(point) {
+            break;
+        }
+        if result.distances.get(&point).is_some_and(|best| *best < distance) {
+            continue;
+        }
+
+        let Some(element) = grid.get(point) else {
+            continue;
+        };
+
+        for (neighbour_element, neighbour) in grid.element_neighbours::<D>(point) {
+            if !passable(neighbour_element) {
+                continue;
+            }
+
+            let neighbour_distance = distance + cost(element, neighbour_element);
+            if result.distances.get(&neighbour).is_some_and(|best| *best <= neighbour_distance) {
+                continue;
+            }
+
+            result.distances.insert(neighbour, neighbour_distance);
+            result.predecessors.insert(neighbour, point);
+            queue.push(Reverse((neighbour_distance + heuristic(neighbour), neighbour_distance, neighbour.x, neighbour.y)));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CardinalDirection, Grid2D, Grid2DParser, InputParser, OrdinalDirection, Point2D, WrappingGrid2D};
+    use super::{a_star, bfs, dijkstra, manhattan_distance};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Tile {
+        Open(u64),
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = ();
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '#' => Ok(Tile::Wall),
+                '.' => Ok(Tile::Open(1)),
+                digit => digit.to_digit(10).map(|cost| Tile::Open(cost as u64)).ok_or(()),
+            }
+        }
+    }
+
+    const MAZE: &str = "\
+        ..#....\n\
+        .##.##.\n\
+        ....#..\n\
+        ###.#.#\n\
+        ......#\n";
+
+    fn maze() -> Grid2D<Tile> {
+        Grid2DParser::parse_input(MAZE).unwrap()
+    }
+
+    fn is_open(tile: &Tile) -> bool {
+        *tile != Tile::Wall
+    }
+
+    fn entry_cost(_: &Tile, to: &Tile) -> u64 {
+        match to {
+            Tile::Open(cost) => *cost,
+            Tile::Wall => unreachable!("Walls are not passable"),
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let result = bfs::<CardinalDirection, _>(&grid, Point2D::new(0, 0), is_open);
+
+        let goal = Point2D::new(6, 0);
+        assert_eq!(result.distance(goal), Some(10));
+
+        let path = result.path_to(goal).unwrap();
+        assert_eq!(path.len(), 11);
+        assert_eq!(path.first(), Some(&Point2D::new(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.windows(2).all(|step| manhattan_distance(step[0], step[1]) == 1));
+
+        assert_eq!(result.distance(Point2D::new(2, 0)), None);
+        assert_eq!(result.path_to(Point2D::new(2, 0)), None);
+
+        let diagonal = bfs::<OrdinalDirection, _>(&grid, Point2D::new(0, 0), is_open);
+        assert_eq!(diagonal.distance(goal), Some(7));
+    }
+
+    #[test]
+    fn test_dijkstra_weighted() {
+        let grid: Grid2D<Tile> = Grid2DParser::parse_input(".9.\n...\n").unwrap();
+        let result = dijkstra::<CardinalDirection, _>(&grid, Point2D::new(0, 0), is_open, entry_cost);
+
+        assert_eq!(result.distance(Point2D::new(2, 0)), Some(4));
+        assert_eq!(
+            result.path_to(Point2D::new(2, 0)).unwrap(),
+            vec![Point2D::new(0, 0), Point2D::new(0, 1), Point2D::new(1, 1), Point2D::new(2, 1), Point2D::new(2, 0)]
+        );
+    }
+
+    #[test]
+    fn test_a_star_matches_dijkstra() {
+        let grid = maze();
+        let (start, goal) = (Point2D::new(0, 0), Point2D::new(5, 3));
+
+        let expected = dijkstra::<CardinalDirection, _>(&grid, start, is_open, entry_cost);
+        let result = a_star::<CardinalDirection, _>(&grid, start, goal, is_open, entry_cost, |point| manhattan_distance(point, goal));
+
+        assert_eq!(result.distance(goal), expected.distance(goal));
+        assert_eq!(result.path_to(goal).unwrap().len() as u64, result.distance(goal).unwrap() + 1);
+        assert!(result.distances.len() <= expected.distances.len());
+    }
+
+    #[test]
+    fn test_search_wrapping_grid() {
+        let grid = WrappingGrid2D::new(maze());
+        let result = bfs::<CardinalDirection, _>(&grid, Point2D::new(0, 0), is_open);
+
+        assert_eq!(result.distance(Point2D::new(6, 0)), Some(1));
+        assert_eq!(result.distance(Point2D::new(0, 4)), Some(1));
+    }
+}
@@ -1,54 +1,315 @@
 use std::fmt::Display;
 use std::any::type_name;
-use log::info;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Duration;
+use log::{error, info};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
 
+use crate::benchmark::{BenchmarkConfig, BenchmarkReport, run_benchmark};
+use crate::memory::{MemoryStats, measure_memory};
+use crate::report::SolveReport;
+use crate::stream::LineStream;
 use crate::{
+    BorrowingInputParser,
     Input, 
-    InputParser
+    LineInputParser,
+    SolutionError,
+    SolveError,
+    SolveErrorKind
 };
 
-fn run_timed<F, T>(name: &str, method: F) -> T
+fn run_timed<F, T>(name: &str, method: F) -> (T, Duration, Option<MemoryStats>)
     where F: FnOnce() -> T 
 {
     let timer = std::time::Instant::now();
-    let result = method();
+    let (result, memory) = measure_memory(method);
     let duration = timer.elapsed();
 
-    log::info!("{name} {duration:?}");
+    match memory {
+        Some(memory) => log::info!("{name} {duration:?} ({memory})"),
+        None => log::info!("{name} {duration:?}"),
+    }
+
+    (result, duration, memory)
+}
 
-    return result;
+fn report_error(problem: &'static str, solution: &'static str, kind: SolveErrorKind) -> SolveError {
+    let err = SolveError {
+        problem,
+        solution,
+        kind
+    };
+    error!("{err}\n");
+    err
 }
 
 pub trait ProblemQuestion: Sized {
-    type Parser: InputParser;
+    type Parser: BorrowingInputParser;
     type Output: Display;
 
-    fn solve<S: Solution<Self>>(input: Input) -> Self::Output {
+    fn solve<S: FallibleSolution<Self>>(input: Input) -> Result<Self::Output, SolveError> {
+        Self::solve_with_report::<S>(input).map(|(output, _)| output)
+    }
+
+    /// Solves `input`, returning the answer alongside a report of how it was reached
+    fn solve_with_report<S: FallibleSolution<Self>>(input: Input) -> Result<(Self::Output, SolveReport), SolveError> {
         let solution_name = type_name::<S>();
         let problem_name = type_name::<Self>();
-        info!("Solving {problem_name} with {solution_name}");
+        let input_source = input.source();
+        info!("Solving {problem_name} with {solution_name} on {input_source}");
 
-        let result = run_timed(
+        let (result, total, _) = run_timed(
             "Total:             ", 
             || {
-                let parsed_input = run_timed(
+                let (parsed_input, parse, parse_memory) = run_timed(
                     "Parse Input:       ", 
                     || input.parse::<Self::Parser>()
                 );
+                let parsed_input = parsed_input.map_err(SolveErrorKind::Parse)?;
 
-                run_timed(
+                let (output, answer, answer_memory) = run_timed(
                     "Calculating Answer:", 
-                    || S::answer(parsed_input)
-                )
+                    || S::try_answer(parsed_input)
+                );
+                let output = output.map_err(SolveErrorKind::Answer)?;
+
+                Ok((output, parse, answer, parse_memory, answer_memory))
             }
         );
 
-        info!("Solution Result: {result}\n");
-        result
+        match result {
+            Ok((output, parse, answer, parse_memory, answer_memory)) => {
+                info!("Solution Result: {output}\n");
+                let report = SolveReport {
+                    day: None,
+                    part: None,
+                    problem: problem_name,
+                    solution: solution_name,
+                    input: input_source.to_string(),
+                    output: output.to_string(),
+                    parse,
+                    answer,
+                    total,
+                    parse_memory,
+                    answer_memory,
+                };
+
+                Ok((output, report))
+            },
+            Err(kind) => Err(report_error(problem_name, solution_name, kind))
+        }
+    }
+
+    /// Solves the lines of `stream` as they are parsed, without collecting them first
+    fn solve_streaming<S>(stream: LineStream<'_, Self::Parser>) -> Result<Self::Output, SolveError>
+        where S: StreamingSolution<Self>, Self::Parser: LineInputParser
+    {
+        Self::solve_streaming_with_report::<S>(stream).map(|(output, _)| output)
+    }
+
+    /// Solves the lines of `stream` as they are parsed, parsing happens while answering so the report
+    /// counts all of its time and memory as part of the answer
+    fn solve_streaming_with_report<S>(mut stream: LineStream<'_, Self::Parser>) -> Result<(Self::Output, SolveReport), SolveError>
+        where S: StreamingSolution<Self>, Self::Parser: LineInputParser
+    {
+        let solution_name = type_name::<S>();
+        let problem_name = type_name::<Self>();
+        let input_source = stream.source().to_string();
+        info!("Streaming {problem_name} with {solution_name} on {input_source}");
+
+        let (output, total, answer_memory) = run_timed(
+            "Total:             ", 
+            || S::answer_stream(stream.by_ref())
+        );
+
+        match stream.into_error() {
+            Some(kind) => Err(report_error(problem_name, solution_name, kind)),
+            None => {
+                info!("Solution Result: {output}\n");
+                let report = SolveReport {
+                    day: None,
+                    part: None,
+                    problem: problem_name,
+                    solution: solution_name,
+                    input: input_source,
+                    output: output.to_string(),
+                    parse: Duration::ZERO,
+                    answer: total,
+                    total,
+                    parse_memory: None,
+                    answer_memory,
+                };
+
+                Ok((output, report))
+            }
+        }
+    }
+
+    /// Solves `input` repeatedly after a warmup, reporting timing statistics for each phase
+    fn benchmark<S: FallibleSolution<Self>>(input: &Input, config: &BenchmarkConfig) -> Result<BenchmarkReport, SolveError> {
+        let solution_name = type_name::<S>();
+        let problem_name = type_name::<Self>();
+        let input_source = input.source();
+        info!("Benchmarking {problem_name} with {solution_name} on {input_source}");
+
+        match run_benchmark::<Self, S>(input, config) {
+            Ok(report) => {
+                info!("{report}\n");
+                Ok(report)
+            },
+            Err(kind) => Err(report_error(problem_name, solution_name, kind))
+        }
     }
 }
 
 
 pub trait Solution<P: ProblemQuestion> {
-    fn answer(input: <P::Parser as InputParser>::Output) -> P::Output;
-}
\ No newline at end of file
+    fn answer(input: <P::Parser as BorrowingInputParser>::Output<'_>) -> P::Output;
+}
+
+/// A solution which may find that no answer exists for its input
+pub trait FallibleSolution<P: ProblemQuestion> {
+    fn try_answer(input: <P::Parser as BorrowingInputParser>::Output<'_>) -> Result<P::Output, SolutionError>;
+}
+
+impl<P: ProblemQuestion, S: Solution<P>> FallibleSolution<P> for S {
+    fn try_answer(input: <P::Parser as BorrowingInputParser>::Output<'_>) -> Result<P::Output, SolutionError> {
+        Ok(S::answer(input))
+    }
+}
+
+/// A solution which consumes parsed lines one at a time, so its input never needs to be held in memory at once
+pub trait StreamingSolution<P: ProblemQuestion>
+    where P::Parser: LineInputParser
+{
+    fn answer_stream(lines: impl Iterator<Item = <P::Parser as LineInputParser>::LineOutput>) -> P::Output;
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => f.pad("1"),
+            Self::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("Unknown part '{s}', expected 1 or 2")),
+        }
+    }
+}
+
+/// Runs some operation over the `ProblemQuestion` and `Solution` that answer a part of a `Day`
+pub trait PartVisitor {
+    type Output;
+
+    fn visit<P: ProblemQuestion, S: FallibleSolution<P>>(self) -> Self::Output;
+}
+
+struct SolveVisitor(Input);
+
+impl PartVisitor for SolveVisitor {
+    type Output = Result<SolveReport, SolveError>;
+
+    fn visit<P: ProblemQuestion, S: FallibleSolution<P>>(self) -> Self::Output {
+        P::solve_with_report::<S>(self.0).map(|(_, report)| report)
+    }
+}
+
+struct ReadArgVisitor<'a>(&'a str);
+
+impl PartVisitor for ReadArgVisitor<'_> {
+    type Output = Result<SolveReport, SolveError>;
+
+    fn visit<P: ProblemQuestion, S: FallibleSolution<P>>(self) -> Self::Output {
+        match Input::from_arg(self.0) {
+            Ok(input) => P::solve_with_report::<S>(input.normalized()).map(|(_, report)| report),
+            Err(err) => Err(report_error(type_name::<P>(), type_name::<S>(), SolveErrorKind::Io(err))),
+        }
+    }
+}
+
+struct BenchmarkVisitor<'a>(&'a Input, &'a BenchmarkConfig);
+
+impl PartVisitor for BenchmarkVisitor<'_> {
+    type Output = Result<BenchmarkReport, SolveError>;
+
+    fn visit<P: ProblemQuestion, S: FallibleSolution<P>>(self) -> Self::Output {
+        P::benchmark::<S>(self.0, self.1)
+    }
+}
+
+/// A day of puzzles, dispatching each part to the `ProblemQuestion` and `Solution` that answers it
+pub trait Day {
+    const NUMBER: u8;
+
+    fn visit_part<V: PartVisitor>(part: Part, visitor: V) -> V::Output;
+
+    fn solve(part: Part, input: Input) -> Result<SolveReport, SolveError> {
+        Self::visit_part(part, SolveVisitor(input))
+            .map(|report| SolveReport {
+                day: Some(Self::NUMBER),
+                part: Some(part),
+                ..report
+            })
+    }
+
+    fn benchmark(part: Part, input: &Input, config: &BenchmarkConfig) -> Result<BenchmarkReport, SolveError> {
+        Self::visit_part(part, BenchmarkVisitor(input, config))
+    }
+
+    /// Solves `part` with its [`StreamingSolution`] over the lines of the file, or stdin for `-`,
+    /// named by `arg`, `None` when the part has no streaming solution
+    fn stream_part(_part: Part, _arg: &str) -> Option<Result<SolveReport, SolveError>> {
+        None
+    }
+
+    /// Solves `part` reading the input named by `arg` a line at a time when the part has a
+    /// [`StreamingSolution`], otherwise the whole input is read first as for [`Day::solve`]
+    fn solve_streaming(part: Part, arg: &str) -> Result<SolveReport, SolveError> {
+        Self::stream_part(part, arg)
+            .unwrap_or_else(|| Self::visit_part(part, ReadArgVisitor(arg)))
+            .map(|report| SolveReport {
+                day: Some(Self::NUMBER),
+                part: Some(part),
+                ..report
+            })
+    }
+
+    /// Entry point for a day's binary, solving every part of the input named on the command line
+    fn run(default_input: Input) -> ExitCode {
+        let input = match Input::from_args_or(default_input) {
+            Ok(input) => input.normalized(),
+            Err(err) => {
+                error!("Could not read input: {err}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let failures = Part::iter()
+            .filter(|part| Self::solve(*part, input.clone()).is_err())
+            .count();
+
+        if failures == 0 {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::marker::PhantomData;
+use std::path::Path;
+
+use crate::input::strip_bom;
+use crate::{Input, InputSource, LineInputParser, SolveErrorKind};
+
+type Lines<'a> = Box<dyn Iterator<Item = std::io::Result<Cow<'a, str>>> + 'a>;
+
+/// Normalises lines as they are read the way [`Input::normalized`] does for a whole input
+///
+/// A byte order mark is stripped from the first line, lone CRs split lines, and blank lines are
+/// held back until a line with content follows, so trailing blank lines are never yielded.
+struct NormalizedLines<'a> {
+    lines: Lines<'a>,
+    is_first: bool,
+    ready: VecDeque<Cow<'a, str>>,
+    blank: Vec<Cow<'a, str>>,
+}
+
+impl<'a> NormalizedLines<'a> {
+    fn new(lines: Lines<'a>) -> Self {
+        Self {
+            lines,
+            is_first: true,
+            ready: VecDeque::new(),
+            blank: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, line: Cow<'a, str>) {
+        if line.trim().is_empty() {
+            self.blank.push(line);
+        } else {
+            self.ready.extend(self.blank.drain(..));
+            self.ready.push_back(line);
+        }
+    }
+}
+
+impl<'a> Iterator for NormalizedLines<'a> {
+    type Item = std::io::Result<Cow<'a, str>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(line) = self.ready.pop_front() {
+                return Some(Ok(line));
+            }
+
+            let mut line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err)),
+            };
+
+            if std::mem::take(&mut self.is_first) {
+                line = match line {
+                    Cow::Borrowed(line) => Cow::Borrowed(strip_bom(line)),
+                    Cow::Owned(line) => Cow::Owned(strip_bom(&line).to_string()),
+                };
+            }
+
+            match line {
+                line if !line.contains('\r') => self.push(line),
+                Cow::Borrowed(line) => line.split('\r').for_each(|part| self.push(Cow::Borrowed(part))),
+                Cow::Owned(line) => line.split('\r').for_each(|part| self.push(Cow::Owned(part.to_string()))),
+            }
+        }
+    }
+}
+
+/// Parses lines on demand, so only the current line is held in memory
+///
+/// Lines are normalised like [`Input::normalized`] as they are read. Iteration stops at the first
+/// line which cannot be read or parsed, the error is then available from [`LineStream::into_error`].
+pub struct LineStream<'a, P: LineInputParser> {
+    lines: NormalizedLines<'a>,
+    source: InputSource,
+    line_number: usize,
+    error: Option<SolveErrorKind>,
+    _parser: PhantomData<P>,
+}
+
+impl<'a, P: LineInputParser> LineStream<'a, P> {
+    pub fn from_input(input: &'a Input) -> Self {
+        Self::from_lines(input.contents().lines().map(|line| Ok(Cow::Borrowed(line))), input.source().clone())
+    }
+
+    pub fn from_reader(reader: impl BufRead + 'a) -> Self {
+        Self::from_lines(reader.lines().map(|line| line.map(Cow::Owned)), InputSource::Memory)
+    }
+
+    fn from_lines(lines: impl Iterator<Item = std::io::Result<Cow<'a, str>>> + 'a, source: InputSource) -> Self {
+        Self {
+            lines: NormalizedLines::new(Box::new(lines)),
+            source,
+            line_number: 0,
+            error: None,
+            _parser: PhantomData,
+        }
+    }
+
+    pub fn source(&self) -> &InputSource {
+        &self.source
+    }
+
+    /// The read or parse error which ended iteration early, if any
+    pub fn into_error(self) -> Option<SolveErrorKind> {
+        self.error
+    }
+}
+
+impl<P: LineInputParser> LineStream<'static, P> {
+    /// Reads lines from a file as they are needed, a file which cannot be opened ends iteration at its first line
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let source = InputSource::File(path.to_path_buf());
+
+        match File::open(path) {
+            Ok(file) => Self::from_lines(BufReader::new(file).lines().map(|line| line.map(Cow::Owned)), source),
+            Err(err) => Self::from_lines(std::iter::once(Err(err)), source),
+        }
+    }
+
+    pub fn from_stdin() -> Self {
+        Self::from_lines(std::io::stdin().lines().map(|line| line.map(Cow::Owned)), InputSource::Stdin)
+    }
+
+    /// Reads lines from a file path, or stdin when given `-`
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::from_stdin(),
+            path => Self::from_file(path),
+        }
+    }
+}
+
+impl<P: LineInputParser> Iterator for LineStream<'_, P> {
+    type Item = P::LineOutput;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        self.line_number += 1;
+        let line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(err) => {
+                self.error = Some(SolveErrorKind::Io(err));
+                return None;
+            }
+        };
+
+        match P::parse_line(&line) {
+            Ok(output) => Some(output),
+            Err(mut err) => {
+                err.line += self.line_number - 1;
+                self.error = Some(SolveErrorKind::Parse(err));
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{FromStrParser, Input, SolveErrorKind};
+    use super::LineStream;
+
+    #[test]
+    fn test_stream_lines() {
+        let stream = LineStream::<FromStrParser<u32>>::from_reader("1\n2\n3\n".as_bytes());
+        assert_eq!(stream.sum::<u32>(), 6);
+    }
+
+    #[test]
+    fn test_stream_stops_at_error() {
+        let input = Input::from_str("1\n2\nx\n4");
+        let mut stream = LineStream::<FromStrParser<u32>>::from_input(&input);
+        assert_eq!(stream.by_ref().collect::<Vec<u32>>(), vec![1, 2]);
+
+        let Some(SolveErrorKind::Parse(error)) = stream.into_error() else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn test_stream_normalizes_lines() {
+        let contents = "\u{feff}1\r\n2\r3\n\n \n4\r\n\n  \r\n";
+        let expected: Vec<String> = Input::from_str(contents).normalized().contents()
+            .lines()
+            .map(str::to_string)
+            .collect();
+
+        let lines = LineStream::<FromStrParser<String>>::from_reader(contents.as_bytes());
+        assert_eq!(lines.collect::<Vec<String>>(), expected);
+
+        let input = Input::from_str(contents);
+        let lines = LineStream::<FromStrParser<String>>::from_input(&input);
+        assert_eq!(lines.collect::<Vec<String>>(), expected);
+    }
+
+    #[test]
+    fn test_stream_file() {
+        let path = std::env::temp_dir().join("common_test_stream_file");
+        std::fs::write(&path, "5\n6\n").unwrap();
+
+        let stream = LineStream::<FromStrParser<u32>>::from_file(&path);
+        assert_eq!(stream.source().to_string(), path.display().to_string());
+        assert_eq!(stream.sum::<u32>(), 11);
+        std::fs::remove_file(&path).unwrap();
+
+        let mut stream = LineStream::<FromStrParser<u32>>::from_arg("does/not/exist");
+        assert_eq!(stream.next(), None);
+        let Some(SolveErrorKind::Io(error)) = stream.into_error() else {
+            panic!("Expected a read error");
+        };
+        assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,179 @@
+use std::{any::type_name, marker::PhantomData, ops::Range, str::FromStr};
+
+use crate::{BorrowingInputParser, Grid2D, ParseError};
+
+/// A cell of a column aligned table, `raw` keeps the padding the value was aligned with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableCell<'a, T> {
+    pub raw: &'a str,
+    pub value: T,
+}
+
+/// How a [`ColumnTableParser`] divides its lines into columns
+pub trait ColumnLayout {
+    /// The character ranges of each column, the last may extend past the end of every line
+    fn columns(input: &str, lines: &[&str]) -> Result<Vec<Range<usize>>, ParseError>;
+}
+
+/// Columns divided by positions which are blank on every line, a short line is blank past its end
+pub struct SeparatorColumns;
+
+impl ColumnLayout for SeparatorColumns {
+    fn columns(_input: &str, lines: &[&str]) -> Result<Vec<Range<usize>>, ParseError> {
+        let mut occupied = Vec::new();
+        for line in lines {
+            for (index, chr) in line.chars().enumerate() {
+                if index >= occupied.len() {
+                    occupied.resize(index + 1, false);
+                }
+                occupied[index] |= !chr.is_whitespace();
+            }
+        }
+
+        let mut columns = Vec::new();
+        let mut start = None;
+        for (index, is_occupied) in occupied.iter().chain([&false]).enumerate() {
+            match (start, is_occupied) {
+                (None, true) => start = Some(index),
+                (Some(column_start), false) => {
+                    columns.push(column_start..index);
+                    start = None;
+                },
+                _ => (),
+            }
+        }
+
+        Ok(columns)
+    }
+}
+
+/// Columns starting at each token of the first line, running up to the next token
+pub struct FirstRowAnchor;
+
+impl ColumnLayout for FirstRowAnchor {
+    fn columns(input: &str, lines: &[&str]) -> Result<Vec<Range<usize>>, ParseError> {
+        anchored_columns(input, lines, lines.first())
+    }
+}
+
+/// Columns starting at each token of the last line, running up to the next token
+pub struct LastRowAnchor;
+
+impl ColumnLayout for LastRowAnchor {
+    fn columns(input: &str, lines: &[&str]) -> Result<Vec<Range<usize>>, ParseError> {
+        anchored_columns(input, lines, lines.last())
+    }
+}
+
+fn anchored_columns(input: &str, lines: &[&str], anchor: Option<&&str>) -> Result<Vec<Range<usize>>, ParseError> {
+    let anchor = anchor.ok_or_else(|| ParseError::at_end(input, "anchor row"))?;
+
+    let mut starts: Vec<usize> = anchor.chars()
+        .enumerate()
+        .scan(true, |after_space, (index, chr)| {
+            let is_start = *after_space && !chr.is_whitespace();
+            *after_space = chr.is_whitespace();
+            Some(is_start.then_some(index))
+        })
+        .flatten()
+        .collect();
+
+    if starts.is_empty() {
+        return Err(ParseError::at(input, anchor, "anchor row with at least one column"));
+    }
+    starts[0] = 0;
+
+    let width = lines.iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or_default();
+    let ends = starts.iter()
+        .skip(1)
+        .copied()
+        .chain([width.max(starts[starts.len() - 1])]);
+
+    Ok(starts.iter().copied().zip(ends).map(|(start, end)| start..end).collect())
+}
+
+/// Parses text aligned into columns into a grid of [`TableCell`]s, one row per line
+///
+/// Each value is parsed from its cell with surrounding whitespace trimmed. Lines which end
+/// before a column have an empty cell there.
+pub struct ColumnTableParser<T: FromStr, L: ColumnLayout = SeparatorColumns> {
+    _value: PhantomData<T>,
+    _layout: PhantomData<L>,
+}
+
+impl<T: FromStr, L: ColumnLayout> ColumnTableParser<T, L> {
+    fn parse_row<'a>(line: &'a str, columns: &[Range<usize>]) -> Result<Vec<TableCell<'a, T>>, ParseError> {
+        let offsets: Vec<usize> = line.char_indices()
+            .map(|(offset, _)| offset)
+            .chain([line.len()])
+            .collect();
+        let byte_offset = |index: usize| offsets[index.min(offsets.len() - 1)];
+
+        columns.iter()
+            .map(|column| {
+                let raw = &line[byte_offset(column.start)..byte_offset(column.end)];
+                let token = raw.trim();
+                let value = T::from_str(token)
+                    .map_err(|_| ParseError::at(line, token, type_name::<T>()))?;
+
+                Ok(TableCell { raw, value })
+            })
+            .collect()
+    }
+}
+
+impl<T: FromStr, L: ColumnLayout> BorrowingInputParser for ColumnTableParser<T, L> {
+    type Output<'a> = Grid2D<TableCell<'a, T>>;
+
+    fn parse_borrowed(input: &str) -> Result<Self::Output<'_>, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let columns = L::columns(input, &lines)?;
+
+        let rows = lines.iter()
+            .map(|line| Self::parse_row(line, &columns).map_err(|err| err.within(input, line)))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        Grid2D::from_rows(input, rows)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BorrowingInputParser, Point2D};
+    use super::{ColumnTableParser, FirstRowAnchor, LastRowAnchor};
+
+    const TABLE: &str = "12 3  7\n 4 56\n+  *  +";
+
+    #[test]
+    fn test_separator_columns() {
+        let grid = ColumnTableParser::<String>::parse_borrowed(TABLE).unwrap();
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.height, 3);
+
+        let raw: Vec<&str> = grid.elements.iter().map(|cell| cell.raw).collect();
+        assert_eq!(raw, vec!["12", "3 ", "7", " 4", "56", "", "+ ", "* ", "+"]);
+    }
+
+    #[test]
+    fn test_anchor_rows() {
+        let grid = ColumnTableParser::<String, LastRowAnchor>::parse_borrowed(TABLE).unwrap();
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.get(Point2D::new(0, 0)).unwrap().raw, "12 ");
+        assert_eq!(grid.get(Point2D::new(1, 1)).unwrap().raw, "56");
+        assert_eq!(grid.get(Point2D::new(2, 1)).unwrap().raw, "");
+        assert_eq!(grid.get(Point2D::new(1, 2)).unwrap().value, "*");
+
+        let grid = ColumnTableParser::<String, FirstRowAnchor>::parse_borrowed("ab cd\n1\n").unwrap();
+        assert_eq!(grid.get(Point2D::new(1, 1)).unwrap().raw, "");
+    }
+
+    #[test]
+    fn test_short_line_error() {
+        let error = ColumnTableParser::<u32, LastRowAnchor>::parse_borrowed("1 2\n3\n+ *").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "u32");
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{FromStrParser, ProblemQuestion, Solution};
+
+/// Sums one number per line
+pub struct SumProblem;
+impl ProblemQuestion for SumProblem {
+    type Parser = FromStrParser<u32>;
+    type Output = u32;
+}
+
+pub struct SumSolution;
+impl Solution<SumProblem> for SumSolution {
+    fn answer(input: Vec<u32>) -> u32 {
+        input.into_iter().sum()
+    }
+}
@@ -0,0 +1,258 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr, parse_macro_input};
+
+enum Segment {
+    Literal(String),
+    Field(String),
+}
+
+/// Splits a pattern such as `"{x},{y}"` into literals and placeholders, `{{` and `}}` escape braces
+fn parse_pattern(pattern: &LitStr) -> syn::Result<Vec<Segment>> {
+    let value = pattern.value();
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = value.chars().peekable();
+
+    while let Some(chr) = chars.next() {
+        match chr {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            },
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            },
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(chr) => name.push(chr),
+                        None => return Err(syn::Error::new(pattern.span(), "Unclosed `{`, write `{{` for a literal brace")),
+                    }
+                }
+
+                if name.trim().is_empty() {
+                    return Err(syn::Error::new(pattern.span(), "Placeholders must name a field, e.g. `{amount}` or `{0}`"));
+                }
+
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Field(name.trim().to_string()));
+            },
+            '}' => return Err(syn::Error::new(pattern.span(), "Unmatched `}`, write `}}` for a literal brace")),
+            chr => literal.push(chr),
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+}
+
+fn find_format(attrs: &[Attribute]) -> Option<syn::Result<LitStr>> {
+    attrs.iter()
+        .find(|attr| attr.path().is_ident("format"))
+        .map(|attr| attr.parse_args::<LitStr>())
+}
+
+/// Builds a block parsing `line` with `pattern` into `constructor` built from `fields`
+fn format_parser(constructor: TokenStream2, fields: &Fields, pattern: &LitStr) -> syn::Result<TokenStream2> {
+    let segments = parse_pattern(pattern)?;
+
+    let field_names: Vec<String> = fields.iter()
+        .enumerate()
+        .map(|(index, field)| field.ident.as_ref().map_or_else(|| index.to_string(), ToString::to_string))
+        .collect();
+
+    let mut placed = vec![false; field_names.len()];
+    let mut segment_tokens = Vec::new();
+
+    for segment in &segments {
+        match segment {
+            Segment::Literal(literal) => segment_tokens.push(quote! { ::common::FormatSegment::Literal(#literal) }),
+            Segment::Field(name) => {
+                let Some(field) = field_names.iter().position(|field_name| field_name == name) else {
+                    return Err(syn::Error::new(pattern.span(), format!("No field named `{name}`")));
+                };
+                if std::mem::replace(&mut placed[field], true) {
+                    return Err(syn::Error::new(pattern.span(), format!("Field `{name}` appears more than once")));
+                }
+
+                segment_tokens.push(quote! { ::common::FormatSegment::Field(#field) });
+            },
+        }
+    }
+
+    if let Some(missing) = placed.iter().position(|placed| !placed) {
+        let name = &field_names[missing];
+        return Err(syn::Error::new(pattern.span(), format!("Field `{name}` does not appear in the format")));
+    }
+
+    let types: Vec<&syn::Type> = fields.iter().map(|field| &field.ty).collect();
+    let slots: Vec<syn::Ident> = (0..types.len()).map(|index| format_ident!("__field_{index}")).collect();
+    let field_indices = 0..types.len();
+
+    // Each accepted token is parsed once, into its field's slot; a field whose token is later
+    // backtracked over is overwritten by the token of the split which finally succeeds
+    let accept = if types.is_empty() {
+        quote! { &mut |_field: usize, _token: &str| false }
+    } else {
+        quote! {
+            &mut |field: usize, token: &str| match field {
+                #( #field_indices => token.parse::<#types>().map(|value| #slots = Some(value)).is_ok(), )*
+                _ => false,
+            }
+        }
+    };
+
+    let values = slots.iter()
+        .map(|slot| quote! { #slot.expect("Accepted tokens are parsed into their field") });
+
+    let construct = match fields {
+        Fields::Named(_) => {
+            let idents = fields.iter().map(|field| &field.ident);
+            quote! { #constructor { #( #idents: #values, )* } }
+        },
+        Fields::Unnamed(_) => quote! { #constructor( #( #values, )* ) },
+        Fields::Unit => quote! { #constructor },
+    };
+
+    Ok(quote! {
+        {
+            const SEGMENTS: &[::common::FormatSegment] = &[ #( #segment_tokens, )* ];
+            let expected: &[&str] = &[ #( ::std::any::type_name::<#types>(), )* ];
+            #( let mut #slots: ::std::option::Option<#types> = None; )*
+            ::common::split_format(line, SEGMENTS, expected, #accept)?;
+            Ok(#construct)
+        }
+    })
+}
+
+/// Implements `LineInputParser` and `FromStr` for a type from `#[format("...")]` patterns
+///
+/// Structs carry one pattern, enums one per variant, tried in declaration order. Placeholders
+/// name a field (`{amount}`, or `{0}` for tuple fields) which is parsed with its `FromStr` impl.
+#[proc_macro_derive(LineParse, attributes(format))]
+pub fn derive_line_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_line_parse(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_line_parse(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = find_format(&input.attrs)
+                .unwrap_or_else(|| Err(syn::Error::new_spanned(name, "Expected a `#[format(\"...\")]` attribute")))?;
+            format_parser(quote! { Self }, &data.fields, &pattern)?
+        },
+        Data::Enum(data) => {
+            if data.variants.is_empty() {
+                return Err(syn::Error::new_spanned(name, "Cannot parse an enum without variants"));
+            }
+
+            let attempts = data.variants.iter()
+                .map(|variant| {
+                    let variant_name = &variant.ident;
+                    let pattern = find_format(&variant.attrs)
+                        .unwrap_or_else(|| Err(syn::Error::new_spanned(variant_name, "Expected a `#[format(\"...\")]` attribute")))?;
+                    let parser = format_parser(quote! { Self::#variant_name }, &variant.fields, &pattern)?;
+
+                    Ok(quote! { |line: &str| -> ::std::result::Result<Self, ::common::ParseError> #parser })
+                })
+                .collect::<syn::Result<Vec<TokenStream2>>>()?;
+
+            quote! {
+                {
+                    let attempts: &[fn(&str) -> ::std::result::Result<Self, ::common::ParseError>] = &[ #( #attempts, )* ];
+                    let mut errors = ::std::vec::Vec::new();
+                    for attempt in attempts {
+                        match attempt(line) {
+                            Ok(value) => return Ok(value),
+                            Err(err) => errors.push(err),
+                        }
+                    }
+
+                    Err(::common::furthest_error(errors))
+                }
+            }
+        },
+        Data::Union(_) => return Err(syn::Error::new_spanned(name, "Cannot derive `LineParse` for a union")),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::common::LineInputParser for #name #type_generics #where_clause {
+            type LineOutput = Self;
+
+            fn parse_line(line: &str) -> ::std::result::Result<Self, ::common::ParseError> #body
+        }
+
+        impl #impl_generics ::std::str::FromStr for #name #type_generics #where_clause {
+            type Err = ::common::ParseError;
+
+            fn from_str(line: &str) -> ::std::result::Result<Self, Self::Err> {
+                <Self as ::common::LineInputParser>::parse_line(line)
+            }
+        }
+    })
+}
+
+/// Implements `DelimitedRecord` for a struct, reading one field per column in declaration order
+#[proc_macro_derive(DelimitedRecord)]
+pub fn derive_delimited_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_delimited_record(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_delimited_record(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(name, "`DelimitedRecord` can only be derived for a struct"));
+    };
+
+    let field_count = data.fields.len();
+    let values = data.fields.iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let ty = &field.ty;
+            quote! { fields[#index].parse::<#ty>(line)? }
+        });
+
+    let construct = match &data.fields {
+        Fields::Named(_) => {
+            let idents = data.fields.iter().map(|field| &field.ident);
+            quote! { Self { #( #idents: #values, )* } }
+        },
+        Fields::Unnamed(_) => quote! { Self( #( #values, )* ) },
+        Fields::Unit => quote! { Self },
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::common::DelimitedRecord for #name #type_generics #where_clause {
+            const FIELDS: ::std::option::Option<usize> = ::std::option::Option::Some(#field_count);
+
+            #[allow(unused_variables)]
+            fn from_fields(
+                line: &str,
+                fields: &[::common::DelimitedField<'_>],
+            ) -> ::std::result::Result<Self, ::common::ParseError> {
+                Ok(#construct)
+            }
+        }
+    })
+}
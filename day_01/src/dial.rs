@@ -1,9 +1,33 @@
+use std::str::FromStr;
+
+use common::LineParse;
 use log::debug;
 
+/// How far to turn the dial, written as plain digits which must fit in an `i32`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Distance(pub i32);
+
 #[derive(Debug)]
+pub struct InvalidDistance;
+
+impl FromStr for Distance {
+    type Err = InvalidDistance;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(InvalidDistance);
+        }
+
+        s.parse().map(Self).map_err(|_| InvalidDistance)
+    }
+}
+
+#[derive(Debug, LineParse)]
 pub enum DialInstruction {
-    Left(i32),
-    Right(i32)
+    #[format("L{0}")]
+    Left(Distance),
+    #[format("R{0}")]
+    Right(Distance)
 }
 
 #[derive(Debug)]
@@ -39,14 +63,14 @@ impl DialPosition {
         }
 
         self.pos = wrap_pos;
-        return click_count;
+        click_count
     }
 
     /// Applies a dial instruction returns the number of times the dial passes through zero
     pub fn apply(&mut self, action: DialInstruction) -> i32 {
         let click_count = match action {
-            DialInstruction::Left(val) => self.move_by(-val),
-            DialInstruction::Right(val) => self.move_by(val),
+            DialInstruction::Left(Distance(val)) => self.move_by(-val),
+            DialInstruction::Right(Distance(val)) => self.move_by(val),
         };
 
         let pos = self.pos();
@@ -56,7 +80,7 @@ impl DialPosition {
     }
 
     pub fn pos(&self) -> i32 {
-        return self.pos;
+        self.pos
     }
 }
 
@@ -81,15 +105,33 @@ pub fn count_clicks(instructions: Vec<DialInstruction>) -> i32 {
 
 #[cfg(test)]
 mod test {
+    use common::LineInputParser;
+
     use super::{
         DialInstruction,
+        Distance,
         count_clicks
     };
 
+    #[test]
+    fn test_parse_unsigned_distance() {
+        assert!(matches!(DialInstruction::parse_line("L68"), Ok(DialInstruction::Left(Distance(68)))));
+
+        let error = DialInstruction::parse_line("L-5").unwrap_err();
+        assert_eq!(error.column, 2);
+
+        let error = DialInstruction::parse_line("R+5").unwrap_err();
+        assert_eq!(error.column, 2);
+
+        let error = DialInstruction::parse_line("R2147483648").unwrap_err();
+        assert_eq!(error.column, 2);
+        assert!(DialInstruction::parse_line("R2147483647").is_ok());
+    }
+
     #[test]
     fn test_rollover() {
         let instructions = vec![
-            DialInstruction::Left(1000)
+            DialInstruction::Left(Distance(1000))
         ];
         assert_eq!(count_clicks(instructions), 10)
     }
@@ -97,7 +139,7 @@ mod test {
     #[test]
     fn test_rollover_remainder() {
         let instructions = vec![
-            DialInstruction::Left(1050)
+            DialInstruction::Left(Distance(1050))
         ];
         assert_eq!(count_clicks(instructions), 11)
     }
@@ -105,7 +147,7 @@ mod test {
     #[test]
     fn test_rollover_remainder_rollover() {
         let instructions = vec![
-            DialInstruction::Left(1051)
+            DialInstruction::Left(Distance(1051))
         ];
         assert_eq!(count_clicks(instructions), 11)
     }
@@ -0,0 +1,38 @@
+mod dial;
+
+use common::Solution;
+
+use dial::{
+    DialInstruction, 
+    count_clicks, 
+    count_zeros
+};
+
+struct DialPasswordSolution;
+impl Solution<ProblemOne> for DialPasswordSolution {
+    fn answer(input: Vec<DialInstruction>) -> usize {
+        count_zeros(input)
+    }
+}
+
+impl Solution<ProblemTwo> for DialPasswordSolution {
+    fn answer(input: Vec<DialInstruction>) -> i32 {
+        count_clicks(input)
+    }
+}
+
+common::aoc_day! {
+    DayOne = 1,
+    ProblemOne {
+        parser: DialInstruction,
+        output: usize,
+        solution: DialPasswordSolution,
+        example: 3,
+    },
+    ProblemTwo {
+        parser: DialInstruction,
+        output: i32,
+        solution: DialPasswordSolution,
+        example: 6,
+    },
+}
@@ -0,0 +1,97 @@
+mod parser;
+mod model;
+
+use common::{Solution, StreamingSolution};
+
+use crate::{model::BatteryBank, parser::BatteryBankParser};
+
+struct DayThreeSolution;
+
+impl Solution<ProblemOne> for DayThreeSolution {
+    fn answer(input: Vec<BatteryBank>) -> u64 {
+        <Self as StreamingSolution<ProblemOne>>::answer_stream(input.into_iter())
+    }
+}
+
+impl Solution<ProblemTwo> for DayThreeSolution {
+    fn answer(input: Vec<BatteryBank>) -> u64 {
+        <Self as StreamingSolution<ProblemTwo>>::answer_stream(input.into_iter())
+    }
+}
+
+impl StreamingSolution<ProblemOne> for DayThreeSolution {
+    fn answer_stream(lines: impl Iterator<Item = BatteryBank>) -> u64 {
+        lines.map(|bank| bank.max_joltage(2)).sum()
+    }
+}
+
+impl StreamingSolution<ProblemTwo> for DayThreeSolution {
+    fn answer_stream(lines: impl Iterator<Item = BatteryBank>) -> u64 {
+        lines.map(|bank| bank.max_joltage(12)).sum()
+    }
+}
+
+common::aoc_day! {
+    DayThree = 3,
+    ProblemOne {
+        parser: BatteryBankParser,
+        output: u64,
+        solution: DayThreeSolution,
+        streaming_solution: DayThreeSolution,
+        example: 357,
+    },
+    ProblemTwo {
+        parser: BatteryBankParser,
+        output: u64,
+        solution: DayThreeSolution,
+        streaming_solution: DayThreeSolution,
+        example: 3121910778619,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Day, Input, LineStream, Part, ProblemQuestion, SolveErrorKind};
+
+    use crate::{DayThree, DayThreeSolution, ProblemOne, ProblemTwo, TEST_EXAMPLE};
+
+    #[test]
+    fn test_problem_one_streaming() {
+        let input = TEST_EXAMPLE;
+        let stream = LineStream::from_input(&input);
+        let result = ProblemOne::solve_streaming::<DayThreeSolution>(stream).unwrap();
+        assert_eq!(result, 357);
+    }
+
+    #[test]
+    fn test_problem_two_streaming_reader() {
+        let input = TEST_EXAMPLE;
+        let stream = LineStream::from_reader(input.contents().as_bytes());
+        let result = ProblemTwo::solve_streaming::<DayThreeSolution>(stream).unwrap();
+        assert_eq!(result, 3121910778619);
+    }
+
+    #[test]
+    fn test_streaming_parse_error() {
+        let input = Input::from_str("987\n12x\n");
+        let stream = LineStream::from_input(&input);
+        let error = ProblemOne::solve_streaming::<DayThreeSolution>(stream).unwrap_err();
+        assert!(error.to_string().contains("line 2, column 3"));
+    }
+
+    #[test]
+    fn test_day_streams_example_file() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/example");
+        let report = DayThree::solve_streaming(Part::Two, path).unwrap();
+        assert_eq!(report.output, "3121910778619");
+        assert_eq!(report.input, path);
+        assert_eq!(report.day, Some(3));
+    }
+
+    #[test]
+    fn test_day_streams_missing_file() {
+        let error = DayThree::solve_streaming(Part::One, "does/not/exist").unwrap_err();
+        assert!(matches!(error.kind, SolveErrorKind::Io(_)));
+        assert!(error.to_string().contains("could not read input"));
+    }
+}
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+#[derive(Debug, PartialEq)]
 pub struct BatteryBank {
     pub batteries: Vec<u8>
 }
@@ -35,7 +36,7 @@ impl BatteryBank {
         output
     }
 
-    fn log_indicies(&self, indices: &Vec<usize>) {
+    fn log_indicies(&self, indices: &[usize]) {
         let highlight_str: String = (0..self.batteries.len())
             .map(|i| if indices.contains(&i) {'*'} else {' '})
             .collect();
@@ -63,31 +64,31 @@ mod test {
 
     #[test]
     fn test_example1_joltage() {
-        let bank = BatteryBankParser::parse_line("987654321111111");
+        let bank = BatteryBankParser::parse_line("987654321111111").unwrap();
         assert_eq!(bank.max_joltage(2), 98);
     }
 
     #[test]
     fn test_example2_joltage() {
-        let bank = BatteryBankParser::parse_line("811111111111119");
+        let bank = BatteryBankParser::parse_line("811111111111119").unwrap();
         assert_eq!(bank.max_joltage(2), 89);
     }
 
     #[test]
     fn test_example3_joltage() {
-        let bank = BatteryBankParser::parse_line("234234234234278");
+        let bank = BatteryBankParser::parse_line("234234234234278").unwrap();
         assert_eq!(bank.max_joltage(2), 78);
     }
 
     #[test]
     fn test_example4_joltage() {
-        let bank = BatteryBankParser::parse_line("818181911112111");
+        let bank = BatteryBankParser::parse_line("818181911112111").unwrap();
         assert_eq!(bank.max_joltage(2), 92);
     }
 
     #[test]
     fn test_equal_first_break() {
-        let bank = BatteryBankParser::parse_line("999991");
+        let bank = BatteryBankParser::parse_line("999991").unwrap();
         assert_eq!(bank.max_joltage(2), 99);
     }
 }
\ No newline at end of file
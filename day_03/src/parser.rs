@@ -1,4 +1,4 @@
-use common::LineInputParser;
+use common::{LineInputFormatter, LineInputParser, ParseError};
 
 use crate::model::BatteryBank;
 
@@ -8,33 +8,52 @@ pub struct BatteryBankParser;
 impl LineInputParser for BatteryBankParser {
     type LineOutput = BatteryBank;
 
-    fn parse_line(line: &str) -> Self::LineOutput {
-        let batteries = line.chars()
-            .map(|chr| chr.to_digit(10).expect("All characters are valid digits") as u8)
-            .collect();
+    fn parse_line(line: &str) -> Result<Self::LineOutput, ParseError> {
+        let batteries = line.char_indices()
+            .map(|(index, chr)| {
+                chr.to_digit(10)
+                    .map(|digit| digit as u8)
+                    .ok_or_else(|| ParseError::at(line, &line[index..], "battery digit"))
+            })
+            .collect::<Result<_, _>>()?;
 
-        BatteryBank {
+        Ok(BatteryBank {
             batteries
-        }
+        })
+    }
+}
+
+impl LineInputFormatter for BatteryBankParser {
+    fn format_line(output: &Self::LineOutput) -> String {
+        output.to_string()
     }
 }
 
 #[cfg(test)]
 mod test {
+    use common::{InputFormatter, assert_round_trip};
+
     use crate::{TEST_EXAMPLE, parser::BatteryBankParser};
 
 
     #[test]
     fn test_parser_ok() {
-        let result = TEST_EXAMPLE.parse::<BatteryBankParser>();
+        let result = TEST_EXAMPLE.parse::<BatteryBankParser>().unwrap();
         assert_eq!(result.len(), 4);
     }
 
     #[test]
     fn test_parser_line() {
-        let result = TEST_EXAMPLE.parse::<BatteryBankParser>();
+        let result = TEST_EXAMPLE.parse::<BatteryBankParser>().unwrap();
 
         let expected: [u8; 15] = [9,8,7,6,5,4,3,2,1,1,1,1,1,1,1];
         assert_eq!(result[0].batteries, expected)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parser_round_trip() {
+        let banks = TEST_EXAMPLE.parse::<BatteryBankParser>().unwrap();
+        assert_round_trip::<BatteryBankParser>(&banks);
+        assert_eq!(BatteryBankParser::format_input(&banks).trim_end(), TEST_EXAMPLE.contents().trim_end());
+    }
+}
@@ -0,0 +1,43 @@
+mod model;
+
+use common::{
+    Grid2D, 
+    Grid2DParser, 
+    Solution
+};
+
+use crate::model::{
+    RoomCell, 
+    count_available_rolls, 
+    count_available_rolls_iterative
+};
+
+struct DayFourSolution;
+
+impl Solution<ProblemOne> for DayFourSolution {
+    fn answer(input: Grid2D<RoomCell>) -> usize {
+        count_available_rolls(&input)
+    }
+}
+
+impl Solution<ProblemTwo> for DayFourSolution {
+    fn answer(mut input: Grid2D<RoomCell>) -> usize {
+        count_available_rolls_iterative(&mut input)
+    }
+}
+
+common::aoc_day! {
+    DayFour = 4,
+    ProblemOne {
+        parser: Grid2DParser<RoomCell>,
+        output: usize,
+        solution: DayFourSolution,
+        example: 13,
+    },
+    ProblemTwo {
+        parser: Grid2DParser<RoomCell>,
+        output: usize,
+        solution: DayFourSolution,
+        example: 43,
+    },
+}
@@ -1,5 +1,5 @@
 use common::{
-    Grid2D, 
+    Grid, 
     OrdinalDirection, 
     Point2D
 };
@@ -35,7 +35,7 @@ impl std::fmt::Display for RoomCell {
 
 const MAX_ROLL_NEIGHBOURS: usize = 4;
 
-pub fn get_available_rolls(input: &Grid2D<RoomCell>) -> impl Iterator<Item = Point2D> {
+pub fn get_available_rolls<G: Grid<Element = RoomCell>>(input: &G) -> impl Iterator<Item = Point2D> {
     let roll_counts= input
         .element_iter_filtered(&RoomCell::PaperRoll)
         .map(|(_cell, point)| {
@@ -52,11 +52,11 @@ pub fn get_available_rolls(input: &Grid2D<RoomCell>) -> impl Iterator<Item = Poi
         .map(|(point, _)| point)
 }
 
-pub fn count_available_rolls(input: &Grid2D<RoomCell>) -> usize {
+pub fn count_available_rolls<G: Grid<Element = RoomCell>>(input: &G) -> usize {
     get_available_rolls(input).count()
 }
 
-pub fn retrieve_available_rolls(input: &mut Grid2D<RoomCell>) -> Vec<Point2D> {
+pub fn retrieve_available_rolls<G: Grid<Element = RoomCell>>(input: &mut G) -> Vec<Point2D> {
     let rolls: Vec<Point2D> = get_available_rolls(input).collect();
 
     // Remove from input
@@ -68,7 +68,7 @@ pub fn retrieve_available_rolls(input: &mut Grid2D<RoomCell>) -> Vec<Point2D> {
     rolls
 }
 
-pub fn count_available_rolls_iterative(input: &mut Grid2D<RoomCell>) -> usize {
+pub fn count_available_rolls_iterative<G: Grid<Element = RoomCell>>(input: &mut G) -> usize {
     let mut total_count = 0;
     loop {
         let roll_count = retrieve_available_rolls(input).len();
@@ -80,4 +80,21 @@ pub fn count_available_rolls_iterative(input: &mut Grid2D<RoomCell>) -> usize {
     } 
 
     total_count
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Grid2DParser, SparseGrid2D};
+
+    use crate::TEST_EXAMPLE;
+    use super::{count_available_rolls, count_available_rolls_iterative};
+
+    #[test]
+    fn test_sparse_grid_rolls() {
+        let dense = TEST_EXAMPLE.parse::<Grid2DParser<_>>().unwrap();
+        let mut sparse = SparseGrid2D::from(dense);
+
+        assert_eq!(count_available_rolls(&sparse), 13);
+        assert_eq!(count_available_rolls_iterative(&mut sparse), 43);
+    }
+}
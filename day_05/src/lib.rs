@@ -0,0 +1,132 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use common::{DualSectionParser, FromStrParser, InclusiveRangeListParser, Solution};
+
+type IngredientListParser = DualSectionParser<InclusiveRangeListParser, FromStrParser<u64>>;
+
+struct DayFiveSolution;
+
+impl DayFiveSolution {
+    fn does_overlap(a: &RangeInclusive<u64>, b: &RangeInclusive<u64>) -> bool {
+        a.contains(b.start()) || a.contains(b.end())
+    }
+
+    fn try_merge(a: &RangeInclusive<u64>, b: &RangeInclusive<u64>) -> Option<RangeInclusive<u64>> {
+        if Self::does_overlap(a, b) {
+            Some(RangeInclusive::new(
+                *a.start().min(b.start()),
+                *a.end().max(b.end()),
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn merge_all(mut ranges: Vec<RangeInclusive<u64>>) -> Vec<RangeInclusive<u64>> {
+        ranges.sort_by_key(|range| *range.start());
+
+        let mut i = 0;
+        while i < ranges.len() - 1 {
+            let a = &ranges[i];
+            let b = &ranges[i + 1];
+
+            match Self::try_merge(a, b) {
+                Some(combined) => {
+                    ranges[i] = combined;
+                    ranges.remove(i + 1);
+                }
+                None => {
+                    i += 1;
+                }
+            }
+        }
+
+        ranges
+    }
+}
+
+impl Solution<ProblemOne> for DayFiveSolution {
+    fn answer(input: (Vec<RangeInclusive<u64>>, Vec<u64>)) -> usize {
+        input
+            .1
+            .into_iter()
+            .filter(|id| input.0.iter().any(|range| range.contains(id)))
+            .count()
+    }
+}
+
+impl Solution<ProblemTwo> for DayFiveSolution {
+    fn answer(input: (Vec<RangeInclusive<u64>>, Vec<u64>)) -> usize {
+        Self::merge_all(input.0)
+            .into_iter()
+            .map(|range| range.count())
+            .sum()
+    }
+}
+
+pub struct DayFiveDumbSolution;
+impl Solution<ProblemTwo> for DayFiveDumbSolution {
+    fn answer(input: (Vec<RangeInclusive<u64>>, Vec<u64>)) -> usize {
+        let ranges = input.0;
+
+        let ids = ranges.into_iter().flat_map(|range| range.into_iter());
+
+        // Will eat all your ram
+        let set = HashSet::<u64>::from_iter(ids);
+
+        set.len()
+    }
+}
+
+common::aoc_day! {
+    DayFive = 5,
+    ProblemOne {
+        parser: IngredientListParser,
+        output: usize,
+        solution: DayFiveSolution,
+        example: 3,
+    },
+    ProblemTwo {
+        parser: IngredientListParser,
+        output: usize,
+        solution: DayFiveSolution,
+        example: 14,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use common::{CrossCheck, Input, ProblemQuestion};
+
+    use crate::{
+        DayFiveDumbSolution, DayFiveSolution, IngredientListParser, ProblemTwo, TEST_EXAMPLE,
+    };
+
+    #[test]
+    fn test_problem_two_example_dumb() {
+        let result = ProblemTwo::solve::<DayFiveDumbSolution>(TEST_EXAMPLE).unwrap();
+        assert_eq!(result, 14);
+    }
+
+    #[test]
+    fn test_problem_two_cross_check() {
+        let report = CrossCheck::<ProblemTwo>::new()
+            .with::<DayFiveSolution>()
+            .with::<DayFiveDumbSolution>()
+            .assert_agree(&TEST_EXAMPLE);
+
+        assert_eq!(report.reports.len(), 2);
+    }
+
+    #[test]
+    fn test_parser() {
+        let (ranges, ids) = Input::parse::<IngredientListParser>(&TEST_EXAMPLE).unwrap();
+        assert_eq!(ranges.len(), 4);
+        assert_eq!(ids.len(), 6);
+
+        assert_eq!(*ranges[0].start(), 3);
+        assert_eq!(*ranges[0].end(), 5);
+        assert_eq!(ids[0], 1);
+        assert_eq!(ids[2], 8);
+    }
+}
@@ -0,0 +1,51 @@
+mod model;
+mod parser;
+use crate::{
+    model::{MathHomework, get_cephalopod_equations, get_equations},
+    parser::{MathHomeworkParser, MathHomeworkSpacePerservingParser},
+};
+use common::Solution;
+
+struct DaySixSolution;
+
+impl Solution<ProblemOne> for DaySixSolution {
+    fn answer(input: MathHomework<'_>) -> u64 {
+        let equations = get_equations(&input);
+        equations
+            .map(|(values, op)| {
+                values
+                    .into_iter()
+                    .fold(op.identity(), |last, item| op.apply(last, item))
+            })
+            .sum()
+    }
+}
+
+impl Solution<ProblemTwo> for DaySixSolution {
+    fn answer(input: MathHomework<'_>) -> u64 {
+        let equations = get_cephalopod_equations(&input);
+        equations
+            .map(|(values, op)| {
+                values
+                    .into_iter()
+                    .fold(op.identity(), |last, item| op.apply(last, item))
+            })
+            .sum()
+    }
+}
+
+common::aoc_day! {
+    DaySix = 6,
+    ProblemOne {
+        parser: MathHomeworkParser,
+        output: u64,
+        solution: DaySixSolution,
+        example: 4277556,
+    },
+    ProblemTwo {
+        parser: MathHomeworkSpacePerservingParser,
+        output: u64,
+        solution: DaySixSolution,
+        example: 3263827,
+    },
+}
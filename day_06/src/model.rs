@@ -4,7 +4,7 @@ use std::{
     str::FromStr,
 };
 
-use common::Grid2D;
+use common::{Grid2D, TableCell};
 
 #[derive(Debug, Clone, Copy)]
 pub enum MathOperator {
@@ -41,9 +41,9 @@ impl Display for MathOperator {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub enum MathCell {
-    Number(u64, String),
+    Number(u64),
     Op(MathOperator),
 }
 
@@ -51,15 +51,11 @@ impl FromStr for MathCell {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.contains('+') {
-            return Ok(Self::Op(MathOperator::Add));
-        } else if s.contains('*') {
-            return Ok(Self::Op(MathOperator::Multiply));
-        } else if let Ok(val) = u64::from_str(s.trim()) {
-            return Ok(Self::Number(val, s.to_string()));
+        match s {
+            "+" => Ok(Self::Op(MathOperator::Add)),
+            "*" => Ok(Self::Op(MathOperator::Multiply)),
+            _ => u64::from_str(s).map(Self::Number).map_err(|_| ()),
         }
-
-        return Err(());
     }
 }
 
@@ -73,14 +69,7 @@ impl MathCell {
 
     pub fn as_num(self) -> Option<u64> {
         match self {
-            Self::Number(val, _) => Some(val),
-            _ => None,
-        }
-    }
-
-    pub fn as_num_str(self) -> Option<String> {
-        match self {
-            Self::Number(_, str) => Some(str),
+            Self::Number(val) => Some(val),
             _ => None,
         }
     }
@@ -89,18 +78,19 @@ impl MathCell {
 impl Display for MathCell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            MathCell::Number(val, _) => write!(f, "{:^5}", val),
+            MathCell::Number(val) => write!(f, "{:^5}", val),
             MathCell::Op(math_operator) => write!(f, "{:^5}", math_operator.to_string()),
         }
     }
 }
 
-pub type MathHomework = Grid2D<MathCell>;
+/// Homework cells keep the padded text of their column, which part two reads vertically
+pub type MathHomework<'a> = Grid2D<TableCell<'a, MathCell>>;
 
-pub fn get_equations(homework: &MathHomework) -> impl Iterator<Item = (Vec<u64>, MathOperator)> {
+pub fn get_equations(homework: &MathHomework<'_>) -> impl Iterator<Item = (Vec<u64>, MathOperator)> {
     homework.columns_iter().map(|column| {
         let mut column: Vec<MathCell> = column
-            .map(|point| homework.get(point).unwrap().clone())
+            .map(|point| homework.get(point).unwrap().value)
             .collect();
         let op = column
             .pop()
@@ -117,19 +107,19 @@ pub fn get_equations(homework: &MathHomework) -> impl Iterator<Item = (Vec<u64>,
 
 /// Warning: Invariant of using Space Perserving Parser is not enforced
 pub fn get_cephalopod_equations(
-    homework: &MathHomework,
+    homework: &MathHomework<'_>,
 ) -> impl Iterator<Item = (Vec<u64>, MathOperator)> {
     homework.columns_iter().map(|column| {
-        let mut column: Vec<MathCell> = column
-            .map(|point| homework.get(point).unwrap().clone())
+        let mut column: Vec<TableCell<MathCell>> = column
+            .map(|point| *homework.get(point).unwrap())
             .collect();
         let op = column
             .pop()
-            .map(|cell| cell.as_op().expect("Last item is operator"))
+            .map(|cell| cell.value.as_op().expect("Last item is operator"))
             .expect("At least one row of operators");
         let value_strs = column
             .into_iter()
-            .map(|cell| cell.as_num_str().expect("Column composed of values"))
+            .map(|cell| cell.value.as_num().map(|_| cell.raw).expect("Column composed of values"))
             .collect();
         let values = cephlapod_transpose(value_strs);
 
@@ -137,7 +127,7 @@ pub fn get_cephalopod_equations(
     })
 }
 
-fn cephlapod_transpose(values: Vec<String>) -> Vec<u64> {
+fn cephlapod_transpose(values: Vec<&str>) -> Vec<u64> {
     let mut output = Vec::new();
 
     let values: Vec<Vec<char>> = values
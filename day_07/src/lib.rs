@@ -0,0 +1,122 @@
+use common::{CardinalDirection, Direction, Grid, Grid2D, Grid2DParser, Point2D, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiagramCell {
+    Start,
+    Splitter,
+    SolvedSplitter(u64),
+    Beam,
+    Empty,
+}
+
+impl TryFrom<char> for DiagramCell {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'S' => Ok(Self::Start),
+            '^' => Ok(Self::Splitter),
+            '|' => Ok(Self::Beam),
+            '.' => Ok(Self::Empty),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for DiagramCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let chr = match self {
+            Self::Start => 'S',
+            Self::Splitter => '^',
+            Self::SolvedSplitter(_) => '!',
+            Self::Beam => '|',
+            Self::Empty => '.',
+        };
+
+        write!(f, "{chr}")
+    }
+}
+
+fn count_splits_recursive(input: &mut Grid2D<DiagramCell>, beam_point: Point2D) -> u64 {
+    const BEAM_DIR: CardinalDirection = CardinalDirection::S;
+    let cell = input.get(beam_point).cloned();
+
+    match cell {
+        Some(DiagramCell::Splitter) => {
+            1 + count_splits_recursive(input, beam_point + BEAM_DIR.rotate().delta())
+                + count_splits_recursive(input, beam_point + BEAM_DIR.rotate_ccw().delta())
+        }
+        Some(DiagramCell::Start) => count_splits_recursive(input, beam_point + BEAM_DIR.delta()),
+        Some(DiagramCell::Empty) => {
+            let cell_mut = input.get_mut(beam_point).unwrap();
+            *cell_mut = DiagramCell::Beam;
+
+            count_splits_recursive(input, beam_point + BEAM_DIR.delta())
+        }
+        _ => 0,
+    }
+}
+
+fn count_timelines_recursive(input: &mut Grid2D<DiagramCell>, beam_point: Point2D) -> u64 {
+    const BEAM_DIR: CardinalDirection = CardinalDirection::S;
+    let cell = input.get(beam_point).cloned();
+
+    match cell {
+        // Wow I got to say rust makes this behaviour read so well
+        Some(DiagramCell::SolvedSplitter(val)) => val,
+        Some(DiagramCell::Splitter) => {
+            let result = count_timelines_recursive(input, beam_point + BEAM_DIR.rotate().delta())
+                + count_timelines_recursive(input, beam_point + BEAM_DIR.rotate_ccw().delta());
+
+            let cell_mut = input.get_mut(beam_point).unwrap();
+            *cell_mut = DiagramCell::SolvedSplitter(result);
+
+            result
+        }
+        Some(DiagramCell::Start) | Some(DiagramCell::Empty)
+            => count_timelines_recursive(input, beam_point + BEAM_DIR.delta()),
+        _ => 1,
+    }
+}
+
+struct DaySevenSolution;
+
+impl Solution<ProblemOne> for DaySevenSolution {
+    fn answer(mut input: Grid2D<DiagramCell>) -> u64 {
+        let start_pos = input
+            .element_iter_filtered(&DiagramCell::Start)
+            .map(|(_, pos)| pos)
+            .next()
+            .expect("starting position");
+
+        count_splits_recursive(&mut input, start_pos)
+    }
+}
+
+impl Solution<ProblemTwo> for DaySevenSolution {
+    fn answer(mut input: Grid2D<DiagramCell>) -> u64 {
+        let start_pos = input
+            .element_iter_filtered(&DiagramCell::Start)
+            .map(|(_, pos)| pos)
+            .next()
+            .expect("starting position");
+
+        count_timelines_recursive(&mut input, start_pos)
+    }
+}
+
+common::aoc_day! {
+    DaySeven = 7,
+    ProblemOne {
+        parser: Grid2DParser<DiagramCell>,
+        output: u64,
+        solution: DaySevenSolution,
+        example: 21,
+    },
+    ProblemTwo {
+        parser: Grid2DParser<DiagramCell>,
+        output: u64,
+        solution: DaySevenSolution,
+        example: 40,
+    },
+}
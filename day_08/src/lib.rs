@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use common::{FallibleSolution, Solution, SolutionError};
+use itertools::Itertools;
+use crate::parser::{Point3D, Point3DParser};
+
+mod parser;
+
+struct DayEightSolution<const N: usize = { usize::MAX }>;
+
+impl<const N: usize> DayEightSolution<N> {
+
+    fn get_shortest_pairs(input: &[Point3D]) -> impl Iterator<Item = (Point3D, Point3D)> {
+        input.iter()
+            .tuple_combinations::<(&Point3D, &Point3D)>()
+            .map(|(a, b)| (*a, *b))
+            .sorted_unstable_by_key(|(a,b)| (*a - *b).square_length())
+            .take(N)
+    }
+
+    fn form_circuits(input: &[Point3D]) -> Vec<HashSet<Point3D>> {
+        let mut circuits: Vec<HashSet<Point3D>> = Vec::new();
+        
+        for (a, b) in Self::get_shortest_pairs(input) {
+            let circuit_a_maybe = circuits.iter().position(|set: &HashSet<Point3D>| set.contains(&a));
+            let circuit_b_maybe = circuits.iter().position(|set: &HashSet<Point3D>| set.contains(&b));
+
+            match (circuit_a_maybe, circuit_b_maybe) {
+                (Some(index_a), Some(index_b)) => {
+                    if index_a != index_b {
+                        let circuit_b = circuits[index_b].clone();
+                        circuits[index_a].extend(circuit_b);
+                        circuits.remove(index_b);
+                    }
+                },
+                (None, Some(index_b)) => {
+                    circuits[index_b].insert(a);
+                },
+                (Some(index_a), None) => {
+                    circuits[index_a].insert(b);
+                },
+                (None, None) => {
+                    circuits.push(HashSet::from([a, b]));
+                }
+            }
+        }
+
+        circuits
+    }
+
+    fn form_circuits_until_complete(input: &[Point3D]) -> Result<(Point3D, Point3D), SolutionError> {
+        let mut circuits: Vec<HashSet<Point3D>> = Vec::new();
+        
+        for (a, b) in Self::get_shortest_pairs(input) {
+            let circuit_a_maybe = circuits.iter().position(|set: &HashSet<Point3D>| set.contains(&a));
+            let circuit_b_maybe = circuits.iter().position(|set: &HashSet<Point3D>| set.contains(&b));
+
+            match (circuit_a_maybe, circuit_b_maybe) {
+                (Some(index_a), Some(index_b)) => {
+                    if index_a != index_b {
+                        let circuit_b = circuits[index_b].clone();
+                        circuits[index_a].extend(circuit_b);
+                        circuits.remove(index_b);
+                    }
+                },
+                (None, Some(index_b)) => {
+                    circuits[index_b].insert(a);
+                },
+                (Some(index_a), None) => {
+                    circuits[index_a].insert(b);
+                },
+                (None, None) => {
+                    circuits.push(HashSet::from([a, b]));
+                }
+            }
+
+            // Check for complete circuit
+            let is_complete = circuits.len() == 1 && circuits[0].len() == input.len();
+            if is_complete {
+                return Ok((a, b))
+            }
+        }
+
+        Err(SolutionError::new("Ran out of connections before forming a complete circuit"))
+    }
+}
+
+impl<const N: usize> Solution<ProblemOne> for DayEightSolution<N> {
+    fn answer(input: Vec<Point3D>) -> u64 {
+        let mut circuits = Self::form_circuits(&input);
+        circuits.sort_by_key(|circuit| circuit.len());
+        
+        circuits.iter().rev()
+            .map(|circuit| circuit.len() as u64)
+            .take(3)
+            .product::<u64>()
+    }
+}
+
+impl<const N: usize> FallibleSolution<ProblemTwo> for DayEightSolution<N> {
+    fn try_answer(input: Vec<Point3D>) -> Result<i64, SolutionError> {
+        let (last_a, last_b) = Self::form_circuits_until_complete(&input)?;
+        println!("{:?} - {:?}", last_a, last_b);
+
+        Ok(last_a.x * last_b.x)
+    }
+}
+
+common::aoc_day! {
+    DayEight = 8,
+    ProblemOne {
+        parser: Point3DParser,
+        output: u64,
+        solution: DayEightSolution<1000>,
+        example_solution: DayEightSolution<10>,
+        example: 40,
+    },
+    ProblemTwo {
+        parser: Point3DParser,
+        output: i64,
+        solution: DayEightSolution,
+        example: 25272,
+    },
+}
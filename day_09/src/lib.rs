@@ -0,0 +1,148 @@
+mod parser;
+use common::{FallibleSolution, SolutionError};
+use parser::{Point2D, Point2DParser};
+use itertools::Itertools;
+
+struct DayNineSolution;
+
+impl DayNineSolution {
+    fn area(a: &Point2D, b: &Point2D) -> u64 {
+        (a.x.abs_diff(b.x)+1)*(a.y.abs_diff(b.y)+1)
+    }
+
+    fn edges(input: &[Point2D]) -> impl Iterator<Item = (Point2D, Point2D)> {
+        input.iter()
+            .cloned()
+            .circular_tuple_windows::<(Point2D, Point2D)>()
+    }
+
+    /// Does any edge of the loop pass through the open interior of the rectangle between two corners
+    fn edge_crosses_interior(input: &[Point2D], a: &Point2D, b: &Point2D) -> bool {
+        let (min_x, max_x) = (a.x.min(b.x), a.x.max(b.x));
+        let (min_y, max_y) = (a.y.min(b.y), a.y.max(b.y));
+
+        Self::edges(input).any(|(p, q)| {
+            let (edge_min_x, edge_max_x) = (p.x.min(q.x), p.x.max(q.x));
+            let (edge_min_y, edge_max_y) = (p.y.min(q.y), p.y.max(q.y));
+
+            if p.x == q.x {
+                min_x < p.x && p.x < max_x && edge_min_y < max_y && edge_max_y > min_y
+            } else {
+                min_y < p.y && p.y < max_y && edge_min_x < max_x && edge_max_x > min_x
+            }
+        })
+    }
+
+    /// Is the point inside or on the loop, coordinates are doubled so midpoints stay integral
+    fn contains_doubled(input: &[Point2D], point: Point2D) -> bool {
+        let mut crossings = 0;
+
+        for (p, q) in Self::edges(input) {
+            let (p, q) = (p * 2, q * 2);
+            let (edge_min_x, edge_max_x) = (p.x.min(q.x), p.x.max(q.x));
+            let (edge_min_y, edge_max_y) = (p.y.min(q.y), p.y.max(q.y));
+
+            let on_edge = (edge_min_x..=edge_max_x).contains(&point.x)
+                && (edge_min_y..=edge_max_y).contains(&point.y);
+            if on_edge {
+                return true;
+            }
+
+            // Cast a ray towards +x counting the vertical edges it crosses
+            if p.x == q.x && p.x > point.x && (edge_min_y..edge_max_y).contains(&point.y) {
+                crossings += 1;
+            }
+        }
+
+        crossings % 2 == 1
+    }
+
+    fn is_enclosed(input: &[Point2D], a: &Point2D, b: &Point2D) -> bool {
+        !Self::edge_crosses_interior(input, a, b)
+            && Self::contains_doubled(input, Point2D::new(a.x + b.x, a.y + b.y))
+    }
+}
+
+impl FallibleSolution<ProblemOne> for DayNineSolution {
+    fn try_answer(input: Vec<Point2D>) -> Result<u64, SolutionError> {
+        input.iter()
+            .tuple_combinations::<(&Point2D, &Point2D)>()
+            .map(|(a, b)| Self::area(a, b))
+            .max()
+            .ok_or_else(|| SolutionError::new("Need at least two red tiles to form a rectangle"))
+    }
+}
+
+impl FallibleSolution<ProblemTwo> for DayNineSolution {
+    fn try_answer(input: Vec<Point2D>) -> Result<u64, SolutionError> {
+        input.iter()
+            .tuple_combinations::<(&Point2D, &Point2D)>()
+            .filter(|(a, b)| Self::is_enclosed(&input, a, b))
+            .map(|(a, b)| Self::area(a, b))
+            .max()
+            .ok_or_else(|| SolutionError::new("No rectangle of red and green tiles exists"))
+    }
+}
+
+common::aoc_day! {
+    DayNine = 9,
+    ProblemOne {
+        parser: Point2DParser,
+        output: u64,
+        solution: DayNineSolution,
+        example: 50,
+    },
+    ProblemTwo {
+        parser: Point2DParser,
+        output: u64,
+        solution: DayNineSolution,
+        example: 24,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Day, Input, Part, ProblemQuestion, SolveError, SolveErrorKind};
+    use crate::{DayNine, DayNineSolution, ProblemOne, ProblemTwo, TEST_EXAMPLE};
+    use crate::parser::{Point2D, Point2DParser};
+
+    fn example_tiles() -> Vec<Point2D> {
+        TEST_EXAMPLE.parse::<Point2DParser>().unwrap()
+    }
+
+    #[test]
+    fn test_problem_one_single_tile() {
+        let result = ProblemOne::solve::<DayNineSolution>(Input::from_str("7,1"));
+        assert!(matches!(result, Err(SolveError { kind: SolveErrorKind::Answer(_), .. })));
+    }
+
+    #[test]
+    fn test_contains_doubled() {
+        let tiles = example_tiles();
+        assert!(DayNineSolution::contains_doubled(&tiles, Point2D::new(22, 2)));
+        assert!(DayNineSolution::contains_doubled(&tiles, Point2D::new(11, 8)));
+        assert!(!DayNineSolution::contains_doubled(&tiles, Point2D::new(6, 4)));
+        assert!(!DayNineSolution::contains_doubled(&tiles, Point2D::new(4, 12)));
+    }
+
+    #[test]
+    fn test_is_enclosed() {
+        let tiles = example_tiles();
+        assert!(DayNineSolution::is_enclosed(&tiles, &Point2D::new(9, 5), &Point2D::new(2, 3)));
+        assert!(DayNineSolution::is_enclosed(&tiles, &Point2D::new(7, 3), &Point2D::new(11, 1)));
+        assert!(!DayNineSolution::is_enclosed(&tiles, &Point2D::new(2, 5), &Point2D::new(11, 1)));
+        assert!(!DayNineSolution::is_enclosed(&tiles, &Point2D::new(7, 1), &Point2D::new(2, 5)));
+    }
+
+    #[test]
+    fn test_problem_two_single_tile() {
+        let result = ProblemTwo::solve::<DayNineSolution>(Input::from_str("7,1"));
+        assert!(matches!(result, Err(SolveError { kind: SolveErrorKind::Answer(_), .. })));
+    }
+
+    #[test]
+    fn test_day_reads_missing_file() {
+        let error = DayNine::solve_streaming(Part::One, "does/not/exist").unwrap_err();
+        assert!(matches!(error.kind, SolveErrorKind::Io(_)));
+    }
+}
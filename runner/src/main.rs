@@ -0,0 +1,371 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Parser;
+use common::{
+    AnswerCheck, AnswerRegistry, BenchmarkConfig, BenchmarkReport, Day, FromStrParser, Input,
+    LineStream, Part, SolveError, SolveReport, input_hash, write_reports_csv, write_reports_json,
+};
+use strum::IntoEnumIterator;
+
+const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+struct DayEntry {
+    number: u8,
+    solve: fn(Part, Input) -> Result<SolveReport, SolveError>,
+    solve_streaming: fn(Part, &str) -> Result<SolveReport, SolveError>,
+    benchmark: fn(Part, &Input, &BenchmarkConfig) -> Result<BenchmarkReport, SolveError>,
+}
+
+impl DayEntry {
+    const fn of<D: Day>() -> Self {
+        Self {
+            number: D::NUMBER,
+            solve: D::solve,
+            solve_streaming: D::solve_streaming,
+            benchmark: D::benchmark,
+        }
+    }
+}
+
+const DAYS: [DayEntry; 9] = [
+    DayEntry::of::<day_01::DayOne>(),
+    DayEntry::of::<day_02::DayTwo>(),
+    DayEntry::of::<day_03::DayThree>(),
+    DayEntry::of::<day_04::DayFour>(),
+    DayEntry::of::<day_05::DayFive>(),
+    DayEntry::of::<day_06::DaySix>(),
+    DayEntry::of::<day_07::DaySeven>(),
+    DayEntry::of::<day_08::DayEight>(),
+    DayEntry::of::<day_09::DayNine>(),
+];
+
+/// Run puzzle solutions for any day and part in the workspace
+#[derive(Parser)]
+struct Args {
+    /// Day to run
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Part to run, both parts are run when omitted
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Run every day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Input to solve: a file in the day's directory (e.g. `input`, `example`), a path, or `-` for stdin
+    #[arg(short, long, default_value = "input")]
+    input: String,
+
+    /// Benchmark each part instead of solving it once
+    #[arg(short, long)]
+    bench: bool,
+
+    /// Read the input a line at a time while solving parts which have a streaming solution
+    #[arg(long, conflicts_with = "bench")]
+    stream: bool,
+
+    /// Timed iterations per part when benchmarking
+    #[arg(long, default_value_t = BenchmarkConfig::default().iterations)]
+    iterations: usize,
+
+    /// Untimed iterations per part before benchmarking
+    #[arg(long, default_value_t = BenchmarkConfig::default().warmup)]
+    warmup: usize,
+
+    /// Write the solve reports to a `.json` or `.csv` file
+    #[arg(short, long)]
+    export: Option<PathBuf>,
+
+    /// Registry of confirmed answers to check against, `answers` in the workspace root when omitted
+    #[arg(long)]
+    answers: Option<PathBuf>,
+
+    /// Record every answer found into the registry, replacing any previously confirmed answer
+    ///
+    /// Answers streamed from stdin are never recorded, their input cannot be read again to hash it
+    #[arg(long, conflicts_with = "bench")]
+    record: bool,
+}
+
+struct RunResult {
+    day: u8,
+    part: Part,
+    report: Result<SolveReport, SolveError>,
+    input_hash: Option<u64>,
+    check: AnswerCheck,
+}
+
+struct BenchResult {
+    day: u8,
+    part: Part,
+    report: Result<BenchmarkReport, SolveError>,
+}
+
+/// A day's input, read whole before solving or named so each part can stream it
+enum DayInput {
+    Loaded(Input),
+    Streamed(String),
+}
+
+fn input_arg(day: u8, name: &str) -> String {
+    if name == "-" || Path::new(name).is_file() {
+        return name.to_string();
+    }
+
+    format!("{WORKSPACE_DIR}/day_{day:02}/{name}")
+}
+
+fn resolve_input(day: u8, name: &str, stream: bool, stdin: &mut Option<Input>) -> std::io::Result<DayInput> {
+    let arg = input_arg(day, name);
+    if !stream && arg == "-" {
+        // Stdin can only be read once, so every day shares the first read
+        let input = match stdin {
+            Some(input) => input.clone(),
+            None => stdin.insert(Input::from_stdin()?.normalized()).clone(),
+        };
+        return Ok(DayInput::Loaded(input));
+    }
+
+    if !stream {
+        return Input::from_arg(&arg).map(|input| DayInput::Loaded(input.normalized()));
+    }
+
+    if arg != "-" && !Path::new(&arg).is_file() {
+        return Err(std::io::Error::new(std::io::ErrorKind::NotFound, "no such file"));
+    }
+    Ok(DayInput::Streamed(arg))
+}
+
+/// Hashes the input answers are confirmed against, a streamed stdin cannot be read a second time so has none
+fn hash_input(input: &DayInput) -> Option<u64> {
+    match input {
+        DayInput::Loaded(input) => Some(input_hash(input.contents().lines())),
+        DayInput::Streamed(arg) if arg == "-" => None,
+        DayInput::Streamed(arg) => {
+            let mut lines = LineStream::<FromStrParser<String>>::from_file(arg);
+            let hash = input_hash(lines.by_ref());
+            lines.into_error().is_none().then_some(hash)
+        }
+    }
+}
+
+fn run_day(entry: &DayEntry, parts: &[Part], input: &DayInput, input_name: &str, registry: &AnswerRegistry) -> Vec<RunResult> {
+    let input_hash = hash_input(input);
+
+    parts.iter()
+        .map(|part| {
+            let report = match input {
+                DayInput::Loaded(input) => (entry.solve)(*part, input.clone()),
+                DayInput::Streamed(arg) => (entry.solve_streaming)(*part, arg),
+            };
+            let check = match (&report, input_hash) {
+                (Ok(report), Some(hash)) => registry.check(entry.number, *part, input_name, hash, &report.output),
+                _ => AnswerCheck::Unknown,
+            };
+
+            RunResult {
+                day: entry.number,
+                part: *part,
+                report,
+                input_hash,
+                check,
+            }
+        })
+        .collect()
+}
+
+fn bench_day(entry: &DayEntry, parts: &[Part], input: &Input, config: &BenchmarkConfig) -> Vec<BenchResult> {
+    parts.iter()
+        .map(|part| BenchResult {
+            day: entry.number,
+            part: *part,
+            report: (entry.benchmark)(*part, input, config),
+        })
+        .collect()
+}
+
+fn print_summary(results: &[RunResult]) {
+    println!("{:>3} {:>4} {:>20} {:>12} {:>8}", "Day", "Part", "Answer", "Time", "Check");
+    for result in results {
+        let Ok(report) = &result.report else {
+            println!("{:>3} {:>4} {:>20}", result.day, result.part, "FAILED");
+            continue;
+        };
+
+        println!(
+            "{:>3} {:>4} {:>20} {:>12} {:>8}",
+            result.day,
+            result.part,
+            report.output,
+            format!("{:.2?}", report.total),
+            result.check
+        );
+    }
+
+    for err in results.iter().filter_map(|result| result.report.as_ref().err()) {
+        eprintln!("{err}");
+    }
+
+    for result in results {
+        if let (AnswerCheck::Fail { expected }, Ok(report)) = (&result.check, &result.report) {
+            eprintln!(
+                "Day {} part {} answered {} but {expected} was confirmed",
+                result.day, result.part, report.output
+            );
+        }
+    }
+}
+
+fn record_answers(path: &Path, mut registry: AnswerRegistry, input_name: &str, results: &[RunResult]) -> std::io::Result<()> {
+    for result in results {
+        if let (Ok(report), Some(hash)) = (&result.report, result.input_hash) {
+            registry.insert(result.day, result.part, input_name, hash, &report.output);
+        }
+    }
+
+    registry.save(path)
+}
+
+fn export_reports(path: &Path, reports: &[SolveReport]) -> std::io::Result<()> {
+    let extension = path.extension().and_then(|extension| extension.to_str());
+    let writer = match extension {
+        Some("json" | "csv") => BufWriter::new(File::create(path)?),
+        _ => return Err(std::io::Error::other("expected a .json or .csv file")),
+    };
+
+    if extension == Some("json") {
+        write_reports_json(reports, writer)
+    } else {
+        write_reports_csv(reports, writer)
+    }
+}
+
+fn print_bench_summary(results: &[BenchResult]) {
+    println!(
+        "{:>3} {:>4} {:>8} {:>12} {:>12} {:>12} {:>12}",
+        "Day", "Part", "Phase", "Min", "Median", "Mean", "Stddev"
+    );
+    for result in results {
+        let Ok(report) = &result.report else {
+            println!("{:>3} {:>4} {:>8}", result.day, result.part, "FAILED");
+            continue;
+        };
+
+        let phases = [("parse", &report.parse), ("answer", &report.answer), ("total", &report.total)];
+        for (phase, stats) in phases {
+            println!(
+                "{:>3} {:>4} {:>8} {:>12} {:>12} {:>12} {:>12}",
+                result.day,
+                result.part,
+                phase,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.stddev),
+            );
+        }
+    }
+
+    for err in results.iter().filter_map(|result| result.report.as_ref().err()) {
+        eprintln!("{err}");
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let parts: Vec<Part> = match args.part {
+        Some(part) => vec![part],
+        None => Part::iter().collect(),
+    };
+
+    let days: Vec<&DayEntry> = match args.day {
+        Some(day) => DAYS.iter().filter(|entry| entry.number == day).collect(),
+        None => DAYS.iter().collect(),
+    };
+
+    if days.is_empty() {
+        eprintln!("No solutions registered for day {}", args.day.unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+
+    if args.stream && args.input == "-" && days.len() * parts.len() > 1 {
+        eprintln!("Streaming from stdin needs a single --day and --part, stdin can only be read once");
+        return ExitCode::FAILURE;
+    }
+
+    let mut inputs = Vec::with_capacity(days.len());
+    let mut stdin = None;
+    for entry in &days {
+        match resolve_input(entry.number, &args.input, args.stream, &mut stdin) {
+            Ok(input) => inputs.push(input),
+            Err(err) => {
+                eprintln!("Could not read input '{}' for day {}: {err}", args.input, entry.number);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let succeeded = if args.bench {
+        let config = BenchmarkConfig {
+            warmup: args.warmup,
+            iterations: args.iterations,
+        };
+
+        let results: Vec<BenchResult> = days.iter().zip(&inputs)
+            .flat_map(|(entry, input)| match input {
+                DayInput::Loaded(input) => bench_day(entry, &parts, input, &config),
+                DayInput::Streamed(_) => unreachable!("Streamed inputs are never benchmarked"),
+            })
+            .collect();
+
+        print_bench_summary(&results);
+        results.iter().all(|result| result.report.is_ok())
+    } else {
+        let answers_path = args.answers.unwrap_or_else(|| [WORKSPACE_DIR, "answers"].iter().collect());
+        let registry = match AnswerRegistry::load(&answers_path) {
+            Ok(registry) => registry,
+            Err(err) => {
+                eprintln!("Could not load answers from '{}': {err}", answers_path.display());
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let results: Vec<RunResult> = days.iter().zip(&inputs)
+            .flat_map(|(entry, input)| run_day(entry, &parts, input, &args.input, &registry))
+            .collect();
+
+        print_summary(&results);
+
+        if args.record && let Err(err) = record_answers(&answers_path, registry, &args.input, &results) {
+            eprintln!("Could not record answers to '{}': {err}", answers_path.display());
+            return ExitCode::FAILURE;
+        }
+
+        if let Some(path) = &args.export {
+            let reports: Vec<SolveReport> = results.iter()
+                .filter_map(|result| result.report.as_ref().ok().cloned())
+                .collect();
+
+            if let Err(err) = export_reports(path, &reports) {
+                eprintln!("Could not export reports to '{}': {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+
+        results.iter().all(|result| {
+            result.report.is_ok() && (args.record || !matches!(result.check, AnswerCheck::Fail { .. }))
+        })
+    };
+
+    if succeeded {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}